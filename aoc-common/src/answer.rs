@@ -0,0 +1,46 @@
+use std::fmt;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    Str(String),
+    /// Letters drawn on a screen, one string per row (day 10).
+    Raster(Vec<String>),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(val) => write!(f, "{}", val),
+            Answer::Str(val) => write!(f, "{}", val),
+            Answer::Raster(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(val: $t) -> Self {
+                    Answer::Int(val as i64)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i32, u32, i64, u64, isize, usize);
+
+impl From<String> for Answer {
+    fn from(val: String) -> Self {
+        Answer::Str(val)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(val: &str) -> Self {
+        Answer::Str(val.to_string())
+    }
+}
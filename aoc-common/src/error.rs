@@ -0,0 +1,74 @@
+use std::error::Error;
+use std::fmt;
+
+/// Why a puzzle could not be solved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// The input is malformed at the given line and column (both 1-based).
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input is well-formed but the puzzle has no solution for it.
+    NoSolution(String),
+    /// The puzzle has no such part: day 25 only has one.
+    NoSuchPart,
+    /// A puzzle parameter is unknown or its value is invalid.
+    Param(String),
+}
+
+impl SolveError {
+    /// Error located at `token`, which must be a slice of `text`.
+    pub fn at(text: &str, token: &str, message: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize).saturating_sub(text.as_ptr() as usize);
+        let before = text.get(..offset).unwrap_or(text);
+        SolveError::Parse {
+            line: before.matches('\n').count() + 1,
+            column: before.chars().rev().take_while(|c| *c != '\n').count() + 1,
+            message: message.into(),
+        }
+    }
+
+    /// Error located right after the end of `text`, e.g. for a missing token.
+    pub fn at_end(text: &str, message: impl Into<String>) -> Self {
+        Self::at(text, &text[text.len()..], message)
+    }
+
+    /// Relocate an error found in a line of the input that is preceded by `lines` lines.
+    pub fn after_lines(self, lines: usize) -> Self {
+        match self {
+            SolveError::Parse {
+                line,
+                column,
+                message,
+            } => SolveError::Parse {
+                line: line + lines,
+                column,
+                message,
+            },
+            other => other,
+        }
+    }
+
+    pub fn no_solution(message: impl Into<String>) -> Self {
+        SolveError::NoSolution(message.into())
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "line {}, column {}: {}", line, column, message),
+            SolveError::NoSolution(message) => write!(f, "no solution: {}", message),
+            SolveError::NoSuchPart => write!(f, "this puzzle has no such part"),
+            SolveError::Param(message) => write!(f, "invalid parameter: {}", message),
+        }
+    }
+}
+
+impl Error for SolveError {}
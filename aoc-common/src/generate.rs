@@ -0,0 +1,43 @@
+use crate::{solve_with, Answer, Solver};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::ops::Range;
+
+/// Random puzzle inputs, for fuzzing and scale testing the solvers.
+pub trait Generator: Solver {
+    /// About the size of the puzzle input.
+    const SIZE: usize;
+
+    /// An input that can be solved with `params`, `size` being a day-specific
+    /// count such as the number of lines, documented by each implementation.
+    fn generate(rng: &mut StdRng, size: usize, params: &Self::Params) -> String;
+}
+
+/// Generate the input of `seed`, always the same one for given arguments.
+pub fn generate<G: Generator>(seed: u64, size: usize, params: &G::Params) -> String {
+    G::generate(&mut StdRng::seed_from_u64(seed), size, params)
+}
+
+/// Generate the input of each seed and solve the `parts` of it with `params`,
+/// returning the inputs with their answers. Panics on the first error, telling
+/// the seed and part, for the tests of the generators.
+pub fn solve_generated<G: Generator>(
+    seeds: Range<u64>,
+    size: usize,
+    params: &G::Params,
+    parts: &[u8],
+) -> Vec<(String, Vec<Answer>)> {
+    seeds
+        .map(|seed| {
+            let input = generate::<G>(seed, size, params);
+            let answers = parts
+                .iter()
+                .map(|&part| match solve_with::<G>(&input, part, params) {
+                    Ok(answer) => answer,
+                    Err(err) => panic!("seed {}, part {}: {}", seed, part, err),
+                })
+                .collect();
+            (input, answers)
+        })
+        .collect()
+}
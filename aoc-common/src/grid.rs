@@ -0,0 +1,232 @@
+use crate::{Point2, SolveError};
+use std::ops::{Index, IndexMut};
+
+/// A rectangular map of cells, indexed by `Point2` from the top left corner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Same as `parse`, but rows shorter than the longest one are padded with `pad`.
+    pub fn parse_padded<F>(input: &str, what: &str, pad: T, cell: F) -> Result<Self, SolveError>
+    where
+        F: FnMut(Point2, char) -> Option<T>,
+    {
+        let (width, rows) = parse_rows(input, what, cell)?;
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for mut row in rows {
+            row.resize(width, pad.clone());
+            cells.extend(row);
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parse one cell per character, `cell` returning `None` for unexpected
+    /// characters and `what` describing the expected ones.
+    pub fn parse<F>(input: &str, what: &str, cell: F) -> Result<Self, SolveError>
+    where
+        F: FnMut(Point2, char) -> Option<T>,
+    {
+        let (width, rows) = parse_rows(input, what, cell)?;
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            let line = input.lines().nth(y).unwrap();
+            return Err(SolveError::at(line, line, "rows have different lengths").after_lines(y));
+        }
+        Ok(Self {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point2) -> bool {
+        0 <= p.x && (p.x as usize) < self.width && 0 <= p.y && (p.y as usize) < self.height
+    }
+
+    pub fn get(&self, p: Point2) -> Option<&T> {
+        self.contains(p)
+            .then(|| &self.cells[p.y as usize * self.width + p.x as usize])
+    }
+
+    pub fn get_mut(&mut self, p: Point2) -> Option<&mut T> {
+        if self.contains(p) {
+            Some(&mut self.cells[p.y as usize * self.width + p.x as usize])
+        } else {
+            None
+        }
+    }
+
+    /// All the points of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point2> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Point2::new(x, y)))
+    }
+
+    /// The cells, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The 4 orthogonal neighbours of `p` within the grid.
+    pub fn neighbours4(&self, p: Point2) -> impl Iterator<Item = Point2> + '_ {
+        p.neighbours4().filter(|n| self.contains(*n))
+    }
+
+    /// The 8 orthogonal or diagonal neighbours of `p` within the grid.
+    pub fn neighbours8(&self, p: Point2) -> impl Iterator<Item = Point2> + '_ {
+        p.neighbours8().filter(|n| self.contains(*n))
+    }
+}
+
+impl<T> Index<Point2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point2) -> &T {
+        match self.get(p) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside of the grid", p),
+        }
+    }
+}
+
+impl<T> IndexMut<Point2> for Grid<T> {
+    fn index_mut(&mut self, p: Point2) -> &mut T {
+        match self.get_mut(p) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside of the grid", p),
+        }
+    }
+}
+
+// The parsed rows, and the length of the longest one.
+fn parse_rows<T, F>(
+    input: &str,
+    what: &str,
+    mut cell: F,
+) -> Result<(usize, Vec<Vec<T>>), SolveError>
+where
+    F: FnMut(Point2, char) -> Option<T>,
+{
+    let mut rows = Vec::new();
+    for (y, line) in input.lines().enumerate() {
+        let mut row = Vec::new();
+        for (x, (index, c)) in line.char_indices().enumerate() {
+            match cell(Point2::new(x as isize, y as isize), c) {
+                Some(value) => row.push(value),
+                None => {
+                    let err = SolveError::at(line, &line[index..], format!("expected {}", what));
+                    return Err(err.after_lines(y));
+                }
+            }
+        }
+        rows.push(row);
+    }
+    match rows.iter().map(|row| row.len()).max() {
+        Some(width) if width > 0 => Ok((width, rows)),
+        _ => Err(SolveError::at_end(input, format!("expected {}", what))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digit(_: Point2, c: char) -> Option<u32> {
+        c.to_digit(10)
+    }
+
+    fn parse_error(line: usize, column: usize, message: &str) -> SolveError {
+        SolveError::Parse {
+            line,
+            column,
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let grid = Grid::parse("123\n456\n", "a digit", digit).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point2::new(2, 1)], 6);
+        let rows: Vec<&[u32]> = grid.rows().collect();
+        assert_eq!(rows, [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(grid.points().nth(4), Some(Point2::new(1, 1)));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_eq!(
+            Grid::parse("123\n4x6", "a digit", digit),
+            Err(parse_error(2, 2, "expected a digit"))
+        );
+        assert_eq!(
+            Grid::parse("123\n45\n789", "a digit", digit),
+            Err(parse_error(2, 1, "rows have different lengths"))
+        );
+        assert_eq!(
+            Grid::parse("", "a digit", digit),
+            Err(parse_error(1, 1, "expected a digit"))
+        );
+        assert_eq!(
+            Grid::parse("\n\n", "a digit", digit),
+            Err(parse_error(3, 1, "expected a digit"))
+        );
+    }
+
+    #[test]
+    fn parse_padded() {
+        let grid = Grid::parse_padded("1\n234\n56", "a digit", 0, digit).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        let rows: Vec<&[u32]> = grid.rows().collect();
+        assert_eq!(rows, [[1, 0, 0], [2, 3, 4], [5, 6, 0]]);
+        assert_eq!(
+            Grid::parse_padded("1\n2 4", "a digit", 0, digit),
+            Err(parse_error(2, 2, "expected a digit"))
+        );
+    }
+
+    #[test]
+    fn neighbours_at_the_edges() {
+        let grid = Grid::new(3, 2, ());
+        let corner: Vec<Point2> = grid.neighbours4(Point2::new(0, 0)).collect();
+        assert_eq!(corner, [Point2::new(1, 0), Point2::new(0, 1)]);
+        assert_eq!(grid.neighbours8(Point2::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours4(Point2::new(1, 1)).count(), 3);
+        assert_eq!(grid.neighbours8(Point2::new(1, 1)).count(), 5);
+        assert_eq!(grid.neighbours8(Point2::new(2, 1)).count(), 3);
+        // Outside, only the neighbours in the grid.
+        let outside: Vec<Point2> = grid.neighbours8(Point2::new(-1, 2)).collect();
+        assert_eq!(outside, [Point2::new(0, 1)]);
+        assert_eq!(grid.get(Point2::new(3, 0)), None);
+    }
+}
@@ -0,0 +1,46 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Where to read a puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::File(path) => std::fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+/// `-` stands for stdin, anything else is a path.
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(arg: &str) -> Result<Self, Self::Err> {
+        Ok(match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        })
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use std::process::ExitCode;
+
+mod answer;
+mod error;
+mod generate;
+mod grid;
+mod input;
+mod params;
+mod parse;
+mod point;
+pub mod trace;
+
+pub use answer::Answer;
+pub use error::SolveError;
+pub use generate::{generate, solve_generated, Generator};
+pub use grid::Grid;
+pub use input::InputSource;
+pub use params::{param_pair, Params};
+pub use parse::{next_token, parse_lines, parse_token, scan};
+pub use point::{Direction, Point2, Point3};
+
+/// A puzzle solution: the input is parsed once and then shared by both parts.
+pub trait Solver {
+    type Input;
+    /// `()` for the days without parameters.
+    type Params: Params;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError>;
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Result<Answer, SolveError>;
+
+    /// Day 25 only has one part, hence the default.
+    fn part2(_input: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        Err(SolveError::NoSuchPart)
+    }
+}
+
+/// Parse `input` and solve one part of it, with the default parameters.
+pub fn solve<S: Solver>(input: &str, part: u8) -> Result<Answer, SolveError> {
+    solve_with::<S>(input, part, &S::Params::default())
+}
+
+/// Parse `input` and solve one part of it.
+pub fn solve_with<S: Solver>(
+    input: &str,
+    part: u8,
+    params: &S::Params,
+) -> Result<Answer, SolveError> {
+    let input = S::parse(input)?;
+    match part {
+        1 => S::part1(&input, params),
+        2 => S::part2(&input, params),
+        _ => Err(SolveError::NoSuchPart),
+    }
+}
+
+/// Print the outcome of a part, moving multi-line answers (e.g. a CRT screen) below the header.
+pub fn print_answer(part: u8, result: &Result<Answer, SolveError>) {
+    match result {
+        Ok(Answer::Raster(rows)) => {
+            println!("Part {}:", part);
+            for row in rows {
+                println!("        {}", row);
+            }
+        }
+        Ok(answer) => println!("Part {}: {}", part, answer),
+        Err(err) => println!("Part {}: error: {}", part, err),
+    }
+}
+
+#[derive(Default)]
+struct Args {
+    input: Option<InputSource>,
+    params: Vec<(String, String)>,
+}
+
+// Parse `--input <path>`, where `-` is stdin, and `--param <name=value>`.
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args::default();
+    while let Some(arg) = args.next() {
+        let (flag, value) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => {
+                (flag.to_string(), Some(value.to_string()))
+            }
+            _ => (arg, None),
+        };
+        if flag != "--input" && flag != "--param" {
+            return Err(format!("unexpected argument '{}'", flag));
+        }
+        let value = match value.or_else(|| args.next()) {
+            Some(value) => value,
+            None => return Err(format!("{} needs a value", flag)),
+        };
+        if flag == "--input" {
+            parsed.input = Some(value.parse().unwrap());
+        } else {
+            parsed.params.push(param_pair(&value)?);
+        }
+    }
+    Ok(parsed)
+}
+
+/// Solve and print both parts of a puzzle, reading the input given on the
+/// command line with `--input <path>` (`-` for stdin), or else `default`.
+/// Parameters are overridden with `--param <name=value>`.
+pub fn run<S: Solver>(default: &str) -> ExitCode {
+    let mut args = std::env::args();
+    let program = args.next().unwrap_or_default();
+    let args = match parse_args(args) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!(
+                "error: {}\nUsage: {} [--input <path>] [--param <name=value>]...",
+                err, program
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+    let params = match S::Params::from_pairs(&args.params) {
+        Ok(params) => params,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let source = args
+        .input
+        .unwrap_or_else(|| InputSource::File(default.into()));
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Cannot read {}: {}", source, err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let input = match S::parse(&input) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}: {}", source, err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut status = ExitCode::SUCCESS;
+    for part in [1, 2] {
+        let result = match part {
+            1 => S::part1(&input, &params),
+            _ => S::part2(&input, &params),
+        };
+        match result {
+            Err(SolveError::NoSuchPart) => continue,
+            Err(_) => status = ExitCode::FAILURE,
+            Ok(_) => (),
+        }
+        print_answer(part, &result);
+    }
+    status
+}
@@ -0,0 +1,84 @@
+use crate::SolveError;
+
+/// Puzzle parameters, e.g. a number of rounds, that can be overridden with
+/// `name=value` pairs. The default values are the ones of the puzzle.
+pub trait Params: Default {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), SolveError>;
+
+    /// Start from the defaults and apply each `name=value` pair.
+    fn from_pairs(pairs: &[(String, String)]) -> Result<Self, SolveError> {
+        let mut params = Self::default();
+        for (name, value) in pairs {
+            params.set(name, value)?;
+        }
+        Ok(params)
+    }
+}
+
+/// Most days have no parameters.
+impl Params for () {
+    fn set(&mut self, name: &str, _value: &str) -> Result<(), SolveError> {
+        Err(SolveError::Param(format!(
+            "unknown parameter '{}', this day has none",
+            name
+        )))
+    }
+}
+
+/// Split a `name=value` command line argument.
+pub fn param_pair(arg: &str) -> Result<(String, String), String> {
+    match arg.split_once('=') {
+        Some((name, value)) => Ok((name.trim().to_string(), value.trim().to_string())),
+        None => Err(format!("expected name=value, found '{}'", arg)),
+    }
+}
+
+/// Declare a struct of parameters with their default values, implementing `Params`.
+#[macro_export]
+macro_rules! params {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident {
+            $($(#[$field_meta:meta])* $field:ident: $ty:ty = $default:expr),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub struct $name {
+            $($(#[$field_meta])* pub $field: $ty),*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self {
+                    $($field: $default),*
+                }
+            }
+        }
+
+        impl $crate::Params for $name {
+            fn set(&mut self, name: &str, value: &str) -> Result<(), $crate::SolveError> {
+                match name {
+                    $(stringify!($field) => {
+                        self.$field = value.parse().map_err(|_| {
+                            $crate::SolveError::Param(format!(
+                                "expected {} for {}, found '{}'",
+                                stringify!($ty),
+                                name,
+                                value
+                            ))
+                        })?;
+                    })*
+                    _ => {
+                        return Err($crate::SolveError::Param(format!(
+                            "unknown parameter '{}', expected one of: {}",
+                            name,
+                            [$(stringify!($field)),*].join(", ")
+                        )))
+                    }
+                }
+                Ok(())
+            }
+        }
+    };
+}
@@ -0,0 +1,139 @@
+use crate::SolveError;
+use std::any::type_name;
+use std::str::FromStr;
+
+/// Parse each line of `input`, locating errors in the whole input.
+pub fn parse_lines<'a, T, F>(input: &'a str, parse: F) -> Result<Vec<T>, SolveError>
+where
+    F: Fn(&'a str) -> Result<T, SolveError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|err| err.after_lines(i)))
+        .collect()
+}
+
+/// Parse `token`, a slice of `text`.
+pub fn parse_token<T: FromStr>(text: &str, token: &str) -> Result<T, SolveError> {
+    token.parse::<T>().map_err(|_| {
+        SolveError::at(
+            text,
+            token,
+            format!("expected {}, found '{}'", type_name::<T>(), token),
+        )
+    })
+}
+
+/// Unwrap the next token of `text`, `what` describing the expected token.
+pub fn next_token<'a>(
+    text: &str,
+    token: Option<&'a str>,
+    what: &str,
+) -> Result<&'a str, SolveError> {
+    token.ok_or_else(|| SolveError::at_end(text, format!("expected {}", what)))
+}
+
+/// Match the whole of `text` against `pattern`, literal text with `{}` fields,
+/// and return the fields as slices of `text`. Each field ends at the first
+/// occurrence of the literal text following it, so fields must be separated by
+/// some, and the last field of a pattern ending with `{}` extends to the end.
+pub fn scan<'a, const N: usize>(text: &'a str, pattern: &str) -> Result<[&'a str; N], SolveError> {
+    let mut literals = pattern.split("{}");
+    let first = literals.next().unwrap_or_default();
+    let mut rest = match text.strip_prefix(first) {
+        Some(rest) => rest,
+        None => {
+            return Err(SolveError::at(
+                text,
+                text,
+                format!("expected '{}'", first.trim()),
+            ))
+        }
+    };
+    let mut fields = [""; N];
+    let mut count = 0;
+    for literal in literals {
+        assert!(count < N, "'{}' has more than {} fields", pattern, N);
+        let end = match literal {
+            "" => rest.len(),
+            _ => rest.find(literal).ok_or_else(|| {
+                SolveError::at_end(text, format!("expected '{}'", literal.trim()))
+            })?,
+        };
+        fields[count] = &rest[..end];
+        rest = &rest[end + literal.len()..];
+        count += 1;
+    }
+    assert_eq!(count, N, "'{}' does not have {} fields", pattern, N);
+    if !rest.is_empty() {
+        return Err(SolveError::at(text, rest, "unexpected text"));
+    }
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(line: usize, column: usize, message: &str) -> SolveError {
+        SolveError::Parse {
+            line,
+            column,
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn scan_fields() {
+        assert_eq!(scan("2-4,6-8", "{}-{},{}-{}"), Ok(["2", "4", "6", "8"]));
+        assert_eq!(
+            scan("move 3 from 1 to 2", "move {} from {} to {}"),
+            Ok(["3", "1", "2"])
+        );
+        // Fields can be empty, and the last one takes the rest of the text.
+        assert_eq!(scan("a=,b=1-2", "a={},b={}"), Ok(["", "1-2"]));
+        assert_eq!(scan("x", "x"), Ok([]));
+    }
+
+    #[test]
+    fn scan_literals() {
+        assert_eq!(
+            scan::<1>("mov 3", "move {}"),
+            Err(parse_error(1, 1, "expected 'move'"))
+        );
+        assert_eq!(
+            scan::<3>("2-4 6-8", "{}-{},{}"),
+            Err(parse_error(1, 8, "expected ','"))
+        );
+    }
+
+    #[test]
+    fn scan_trailing_input() {
+        assert_eq!(
+            scan::<1>("x=2, y=3", "x={},"),
+            Err(parse_error(1, 5, "unexpected text"))
+        );
+        assert_eq!(
+            scan::<0>("noop 1", "noop"),
+            Err(parse_error(1, 5, "unexpected text"))
+        );
+    }
+
+    #[test]
+    fn scan_error_location() {
+        let input = "1-2\n3-4\n5+6";
+        assert_eq!(
+            parse_lines(input, |line| scan::<2>(line, "{}-{}")),
+            Err(parse_error(3, 4, "expected '-'"))
+        );
+        let input = "1-2\nx-3";
+        assert_eq!(
+            parse_lines(input, |line| {
+                let [a, b] = scan(line, "{}-{}")?;
+                Ok((parse_token::<u8>(line, a)?, parse_token::<u8>(line, b)?))
+            }),
+            Err(parse_error(2, 1, "expected u8, found 'x'"))
+        );
+    }
+}
@@ -0,0 +1,199 @@
+use std::ops::{Add, AddAssign, Mul, Sub};
+
+/// A position on a 2D map, `y` growing downwards as the lines of the input.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+const OFFSETS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+impl Point2 {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    /// The next point in direction `dir`.
+    pub fn step(self, dir: Direction) -> Self {
+        self + dir.offset()
+    }
+
+    /// The 4 orthogonally adjacent points.
+    pub fn neighbours4(self) -> impl Iterator<Item = Point2> {
+        Direction::ALL.into_iter().map(move |dir| self.step(dir))
+    }
+
+    /// The 8 orthogonally or diagonally adjacent points.
+    pub fn neighbours8(self) -> impl Iterator<Item = Point2> {
+        OFFSETS8
+            .into_iter()
+            .map(move |(x, y)| self + Point2::new(x, y))
+    }
+
+    pub fn manhattan(self, other: Point2) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Add for Point2 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point2 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point2 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<isize> for Point2 {
+    type Output = Self;
+
+    fn mul(self, factor: isize) -> Self {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+/// A position in space.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+
+impl Point3 {
+    pub const fn new(x: isize, y: isize, z: isize) -> Self {
+        Self { x, y, z }
+    }
+
+    /// The 6 points sharing a face with this one.
+    pub fn neighbours6(self) -> impl Iterator<Item = Point3> {
+        [
+            (0, 0, 1),
+            (0, 0, -1),
+            (0, 1, 0),
+            (0, -1, 0),
+            (1, 0, 0),
+            (-1, 0, 0),
+        ]
+        .into_iter()
+        .map(move |(x, y, z)| self + Point3::new(x, y, z))
+    }
+}
+
+impl Add for Point3 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+/// One of the 4 orthogonal directions on a map, clockwise from `Right`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Right = 0,
+    Down = 1,
+    Left = 2,
+    Up = 3,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Self::Right, Self::Down, Self::Left, Self::Up];
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Self::Right => Self::Up,
+            Self::Down => Self::Right,
+            Self::Left => Self::Down,
+            Self::Up => Self::Left,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+            Self::Up => Self::Right,
+        }
+    }
+
+    pub fn u_turn(self) -> Self {
+        match self {
+            Self::Right => Self::Left,
+            Self::Down => Self::Up,
+            Self::Left => Self::Right,
+            Self::Up => Self::Down,
+        }
+    }
+
+    /// The move of one step in this direction.
+    pub fn offset(self) -> Point2 {
+        match self {
+            Self::Right => Point2::new(1, 0),
+            Self::Down => Point2::new(0, 1),
+            Self::Left => Point2::new(-1, 0),
+            Self::Up => Point2::new(0, -1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.u_turn());
+            assert_eq!(dir.turn_left().turn_left(), dir.u_turn());
+            assert_eq!(dir.u_turn().u_turn(), dir);
+            // ALL is in clockwise order.
+            assert_eq!(dir.turn_right(), Direction::ALL[(dir as usize + 1) % 4]);
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Right.turn_left(), Direction::Up);
+    }
+
+    #[test]
+    fn offsets() {
+        let p = Point2::new(2, 5);
+        assert_eq!(p.step(Direction::Right), Point2::new(3, 5));
+        // Down is towards the next line.
+        assert_eq!(p.step(Direction::Down), Point2::new(2, 6));
+        assert_eq!(p.step(Direction::Left), Point2::new(1, 5));
+        assert_eq!(p.step(Direction::Up), Point2::new(2, 4));
+        for dir in Direction::ALL {
+            assert_eq!(dir.offset() + dir.u_turn().offset(), Point2::default());
+            assert_eq!(p.step(dir).manhattan(p), 1);
+        }
+        assert_eq!(p.neighbours4().count(), 4);
+        assert!(p
+            .neighbours8()
+            .all(|n| n != p && (n - p).x.abs() <= 1 && (n - p).y.abs() <= 1));
+    }
+}
@@ -0,0 +1,249 @@
+use crate::{Grid, Point2};
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+// Pause between frames on the terminal, for them to be seen.
+const FRAME_DELAY: Duration = Duration::from_millis(40);
+// Side of the square of pixels showing a cell in the images.
+const SCALE: usize = 4;
+
+/// A picture of a simulation at some step, one character per cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    title: String,
+    cells: Grid<char>,
+}
+
+impl Frame {
+    pub fn new(title: impl Into<String>, cells: Grid<char>) -> Self {
+        Self {
+            title: title.into(),
+            cells,
+        }
+    }
+
+    /// The smallest frame showing all the `points`, over a background of '.'.
+    pub fn from_points(
+        title: impl Into<String>,
+        points: impl IntoIterator<Item = (Point2, char)>,
+    ) -> Self {
+        let points: Vec<(Point2, char)> = points.into_iter().collect();
+        let min = |coord: fn(&Point2) -> isize| points.iter().map(|(p, _)| coord(p)).min();
+        let max = |coord: fn(&Point2) -> isize| points.iter().map(|(p, _)| coord(p)).max();
+        let corner = Point2::new(min(|p| p.x).unwrap_or(0), min(|p| p.y).unwrap_or(0));
+        let width = max(|p| p.x).map_or(0, |x| x - corner.x + 1) as usize;
+        let height = max(|p| p.y).map_or(0, |y| y - corner.y + 1) as usize;
+        let mut cells = Grid::new(width, height, '.');
+        for (p, c) in points {
+            cells[p - corner] = c;
+        }
+        Self::new(title, cells)
+    }
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.title)?;
+        for row in self.cells.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+/// Where the frames go: `term`, `log:<path>`, `ppm:<dir>` or `png:<dir>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceTarget {
+    /// Drawn in the terminal, on stderr.
+    Terminal,
+    /// Appended one after the other to a text file.
+    Log(PathBuf),
+    /// One image per frame in a directory.
+    Ppm(PathBuf),
+    Png(PathBuf),
+}
+
+impl FromStr for TraceTarget {
+    type Err = String;
+
+    fn from_str(arg: &str) -> Result<Self, Self::Err> {
+        match arg.split_once(':') {
+            None if arg == "term" => Ok(TraceTarget::Terminal),
+            Some(("log", path)) => Ok(TraceTarget::Log(PathBuf::from(path))),
+            Some(("ppm", dir)) => Ok(TraceTarget::Ppm(PathBuf::from(dir))),
+            Some(("png", dir)) => Ok(TraceTarget::Png(PathBuf::from(dir))),
+            _ => Err("expected term, log:<path>, ppm:<dir> or png:<dir>".to_string()),
+        }
+    }
+}
+
+impl fmt::Display for TraceTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TraceTarget::Terminal => write!(f, "the terminal"),
+            TraceTarget::Log(path) | TraceTarget::Ppm(path) | TraceTarget::Png(path) => {
+                write!(f, "{}", path.display())
+            }
+        }
+    }
+}
+
+enum Sink {
+    Terminal,
+    Log(BufWriter<File>),
+    Images { dir: PathBuf, png: bool },
+}
+
+impl Sink {
+    fn open(target: &TraceTarget) -> io::Result<Self> {
+        Ok(match target {
+            TraceTarget::Terminal => Sink::Terminal,
+            TraceTarget::Log(path) => Sink::Log(BufWriter::new(File::create(path)?)),
+            TraceTarget::Ppm(dir) | TraceTarget::Png(dir) => {
+                fs::create_dir_all(dir)?;
+                let png = matches!(target, TraceTarget::Png(_));
+                Sink::Images {
+                    dir: dir.clone(),
+                    png,
+                }
+            }
+        })
+    }
+
+    fn write(&mut self, number: usize, frame: &Frame) -> io::Result<()> {
+        match self {
+            Sink::Terminal => {
+                // Clear the screen to draw each frame at the same place.
+                eprint!("\x1b[2J\x1b[H{}", frame);
+                thread::sleep(FRAME_DELAY);
+                Ok(())
+            }
+            Sink::Log(file) => writeln!(file, "{}", frame),
+            Sink::Images { dir, png: false } => {
+                let path = dir.join(format!("frame{:06}.ppm", number));
+                let (width, height, pixels) = pixels(&frame.cells);
+                let mut file = BufWriter::new(File::create(path)?);
+                write!(file, "P6\n{} {}\n255\n", width, height)?;
+                file.write_all(&pixels)?;
+                file.flush()
+            }
+            Sink::Images { dir, png: true } => {
+                let path = dir.join(format!("frame{:06}.png", number));
+                let (width, height, pixels) = pixels(&frame.cells);
+                let file = BufWriter::new(File::create(path)?);
+                let mut encoder = png::Encoder::new(file, width as u32, height as u32);
+                encoder.set_color(png::ColorType::Rgb);
+                encoder.set_depth(png::BitDepth::Eight);
+                let mut writer = encoder.write_header()?;
+                writer.write_image_data(&pixels)?;
+                Ok(writer.finish()?)
+            }
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Sink::Log(file) => file.flush(),
+            _ => Ok(()),
+        }
+    }
+}
+
+// Colour of a cell in the images, the usual characters having their own.
+fn colour(c: char) -> [u8; 3] {
+    match c {
+        '.' | ' ' => [20, 20, 30],
+        '#' => [130, 130, 140],
+        'o' => [230, 200, 90],
+        '@' => [230, 80, 60],
+        'H' => [90, 210, 90],
+        '<' | '>' | '^' | 'v' => [120, 170, 250],
+        '0'..='9' => {
+            let level = c.to_digit(10).unwrap() as u8;
+            [40 + 20 * level, 40 + 20 * level, 40]
+        }
+        _ => {
+            let hash = (c as u32).wrapping_mul(2654435761);
+            [
+                hash as u8 | 64,
+                (hash >> 8) as u8 | 64,
+                (hash >> 16) as u8 | 64,
+            ]
+        }
+    }
+}
+
+// Width, height and RGB bytes of the image of `cells`.
+fn pixels(cells: &Grid<char>) -> (usize, usize, Vec<u8>) {
+    let (width, height) = (cells.width() * SCALE, cells.height() * SCALE);
+    let mut pixels = Vec::with_capacity(width * height * 3);
+    for row in cells.rows() {
+        for _ in 0..SCALE {
+            for &c in row {
+                for _ in 0..SCALE {
+                    pixels.extend(colour(c));
+                }
+            }
+        }
+    }
+    (width, height, pixels)
+}
+
+struct Tracer {
+    sink: Sink,
+    every: usize,
+    count: usize,
+    error: Option<io::Error>,
+}
+
+static TRACING: AtomicBool = AtomicBool::new(false);
+static TRACER: Mutex<Option<Tracer>> = Mutex::new(None);
+
+/// Send the frames of the simulations to `target` from now on, keeping only
+/// one out of `every`.
+pub fn start(target: &TraceTarget, every: usize) -> io::Result<()> {
+    let tracer = Tracer {
+        sink: Sink::open(target)?,
+        every: every.max(1),
+        count: 0,
+        error: None,
+    };
+    *TRACER.lock().unwrap() = Some(tracer);
+    TRACING.store(true, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Stop tracing, reporting the first error met while writing the frames.
+pub fn stop() -> io::Result<()> {
+    TRACING.store(false, Ordering::Relaxed);
+    match TRACER.lock().unwrap().take() {
+        Some(mut tracer) => match tracer.error {
+            Some(err) => Err(err),
+            None => tracer.sink.flush(),
+        },
+        None => Ok(()),
+    }
+}
+
+/// Record the frame drawn by `frame`, which is only called when tracing.
+pub fn trace(frame: impl FnOnce() -> Frame) {
+    if !TRACING.load(Ordering::Relaxed) {
+        return;
+    }
+    if let Some(tracer) = TRACER.lock().unwrap().as_mut() {
+        let number = tracer.count;
+        tracer.count += 1;
+        if number % tracer.every == 0 && tracer.error.is_none() {
+            if let Err(err) = tracer.sink.write(number / tracer.every, &frame()) {
+                tracer.error = Some(err);
+            }
+        }
+    }
+}
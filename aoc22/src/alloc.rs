@@ -0,0 +1,106 @@
+use serde::Serialize;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering};
+
+// The system allocator, which once enabled also keeps count of the heap in use,
+// of the allocations and of their peak. Until then it only checks the flag.
+pub struct Counting;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+// Bytes allocated minus bytes freed since enabled, which is negative when more
+// was freed than allocated since.
+static IN_USE: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+fn allocated(size: usize) {
+    if ENABLED.load(Ordering::Relaxed) {
+        let in_use = IN_USE.fetch_add(size as isize, Ordering::Relaxed) + size as isize;
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(size, Ordering::Relaxed);
+        PEAK.fetch_max(in_use, Ordering::Relaxed);
+    }
+}
+
+fn freed(size: usize) {
+    if ENABLED.load(Ordering::Relaxed) {
+        IN_USE.fetch_sub(size as isize, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        freed(layout.size());
+    }
+
+    // Counted as a new allocation, the old one being freed.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            freed(layout.size());
+            allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+// Heap used by a part of a day.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Usage {
+    // Bytes held by the parsed input.
+    pub input: usize,
+    // Most bytes in use at once while solving, the input excluded.
+    pub peak: usize,
+    pub allocations: usize,
+    // Bytes allocated in total while solving.
+    pub allocated: usize,
+}
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+// Start counting from now on, returning the bytes in use.
+pub fn start() -> isize {
+    let in_use = IN_USE.load(Ordering::Relaxed);
+    PEAK.store(in_use, Ordering::Relaxed);
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+    ALLOCATED.store(0, Ordering::Relaxed);
+    in_use
+}
+
+pub fn in_use() -> isize {
+    IN_USE.load(Ordering::Relaxed)
+}
+
+// The usage since `start` returned `start`, the input holding `input` bytes.
+pub fn usage(start: isize, input: usize) -> Usage {
+    Usage {
+        input,
+        peak: (PEAK.load(Ordering::Relaxed) - start).max(0) as usize,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        allocated: ALLOCATED.load(Ordering::Relaxed),
+    }
+}
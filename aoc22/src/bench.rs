@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+use std::fs::{read_to_string, write};
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+// Timings of one phase (parse, part1 or part2) of a day.
+pub struct Phase {
+    pub name: &'static str,
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Phase {
+    pub fn new(name: &'static str, mut times: Vec<Duration>) -> Self {
+        times.sort();
+        let percentile = |p: usize| times[((times.len() * p).div_ceil(100)).max(1) - 1];
+        Phase {
+            name,
+            runs: times.len(),
+            min: times[0],
+            median: percentile(50),
+            p95: percentile(95),
+        }
+    }
+}
+
+// Median of each (day, phase) in a file written by `save`.
+pub type Baseline = HashMap<(u8, String), Duration>;
+
+pub fn load(path: &Path) -> io::Result<Baseline> {
+    let mut baseline = Baseline::new();
+    for line in read_to_string(path)?.lines() {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        if let [day, phase, _runs, _min, median, _p95] = tokens[..] {
+            let day = day.trim_start_matches("day").parse::<u8>();
+            if let (Ok(day), Ok(median)) = (day, median.parse::<u64>()) {
+                baseline.insert((day, phase.to_string()), Duration::from_nanos(median));
+            }
+        }
+    }
+    Ok(baseline)
+}
+
+pub fn save(path: &Path, results: &[(u8, Vec<Phase>)]) -> io::Result<()> {
+    let mut out = String::from("# day phase runs min_ns median_ns p95_ns\n");
+    for (day, phases) in results {
+        for phase in phases {
+            out.push_str(&format!(
+                "day{:02} {} {} {} {} {}\n",
+                day,
+                phase.name,
+                phase.runs,
+                phase.min.as_nanos(),
+                phase.median.as_nanos(),
+                phase.p95.as_nanos()
+            ));
+        }
+    }
+    write(path, out)
+}
+
+pub fn print(day: u8, phase: &Phase, baseline: Option<&Baseline>) {
+    let change = baseline
+        .and_then(|baseline| baseline.get(&(day, phase.name.to_string())))
+        .map(|before| {
+            let ratio = phase.median.as_secs_f64() / before.as_secs_f64();
+            format!("  {:+.1}%", 100.0 * (ratio - 1.0))
+        })
+        .unwrap_or_default();
+    println!(
+        "day{:02} {:<6} min {:>12}  median {:>12}  p95 {:>12}{}",
+        day,
+        phase.name,
+        format!("{:.2?}", phase.min),
+        format!("{:.2?}", phase.median),
+        format!("{:.2?}", phase.p95),
+        change
+    );
+}
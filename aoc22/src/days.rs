@@ -0,0 +1,151 @@
+use crate::alloc::{self, Usage};
+use crate::bench::Phase;
+use aoc_common::{Answer, Generator, Params, SolveError, Solver};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+pub const DAYS: u8 = 25;
+
+pub struct Solution {
+    pub part: u8,
+    pub answer: Result<Answer, SolveError>,
+    // Time spent in the part, parsing excluded.
+    pub elapsed: Duration,
+    // Only measured when the allocations are counted.
+    pub memory: Option<Usage>,
+}
+
+// Parse the input once, then solve the requested parts.
+fn solve<S: Solver>(
+    input: &str,
+    parts: &[u8],
+    params: &[(String, String)],
+) -> Result<Vec<Solution>, SolveError> {
+    let params = S::Params::from_pairs(params)?;
+    let before = alloc::in_use();
+    let input = S::parse(input)?;
+    let input_bytes = (alloc::in_use() - before).max(0) as usize;
+    let mut solutions = Vec::new();
+    for &part in parts {
+        let start_bytes = alloc::start();
+        let start = Instant::now();
+        let answer = match part {
+            1 => S::part1(&input, &params),
+            2 => S::part2(&input, &params),
+            _ => Err(SolveError::NoSuchPart),
+        };
+        let elapsed = start.elapsed();
+        let memory = alloc::enabled().then(|| alloc::usage(start_bytes, input_bytes));
+        solutions.push(Solution {
+            part,
+            answer,
+            elapsed,
+            memory,
+        });
+    }
+    Ok(solutions)
+}
+
+// Time `runs` executions of each phase of a day, after a warm-up run that
+// also checks the phase succeeds.
+fn bench<S: Solver>(input: &str, runs: usize) -> Result<Vec<Phase>, SolveError> {
+    let params = S::Params::default();
+    let parsed = S::parse(input)?;
+    let times = (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(S::parse(input)).ok();
+            start.elapsed()
+        })
+        .collect();
+    let mut phases = vec![Phase::new("parse", times)];
+    for (name, part) in [("part1", 1), ("part2", 2)] {
+        let solve = || match part {
+            1 => S::part1(&parsed, &params),
+            _ => S::part2(&parsed, &params),
+        };
+        match solve() {
+            Err(SolveError::NoSuchPart) => continue,
+            Err(err) => return Err(err),
+            Ok(_) => (),
+        }
+        let times = (0..runs)
+            .map(|_| {
+                let start = Instant::now();
+                black_box(solve()).ok();
+                start.elapsed()
+            })
+            .collect();
+        phases.push(Phase::new(name, times));
+    }
+    Ok(phases)
+}
+
+// Random input of `seed`, about the size of the puzzle input by default.
+fn generate<G: Generator>(
+    seed: u64,
+    size: Option<usize>,
+    params: &[(String, String)],
+) -> Result<String, SolveError> {
+    let params = G::Params::from_pairs(params)?;
+    Ok(aoc_common::generate::<G>(
+        seed,
+        size.unwrap_or(G::SIZE),
+        &params,
+    ))
+}
+
+macro_rules! dispatch {
+    ($day:expr, $f:ident($($arg:expr),*)) => {
+        match $day {
+            1 => $f::<day01::Day01>($($arg),*),
+            2 => $f::<day02::Day02>($($arg),*),
+            3 => $f::<day03::Day03>($($arg),*),
+            4 => $f::<day04::Day04>($($arg),*),
+            5 => $f::<day05::Day05>($($arg),*),
+            6 => $f::<day06::Day06>($($arg),*),
+            7 => $f::<day07::Day07>($($arg),*),
+            8 => $f::<day08::Day08>($($arg),*),
+            9 => $f::<day09::Day09>($($arg),*),
+            10 => $f::<day10::Day10>($($arg),*),
+            11 => $f::<day11::Day11>($($arg),*),
+            12 => $f::<day12::Day12>($($arg),*),
+            13 => $f::<day13::Day13>($($arg),*),
+            14 => $f::<day14::Day14>($($arg),*),
+            15 => $f::<day15::Day15>($($arg),*),
+            16 => $f::<day16::Day16>($($arg),*),
+            17 => $f::<day17::Day17>($($arg),*),
+            18 => $f::<day18::Day18>($($arg),*),
+            19 => $f::<day19::Day19>($($arg),*),
+            20 => $f::<day20::Day20>($($arg),*),
+            21 => $f::<day21::Day21>($($arg),*),
+            22 => $f::<day22::Day22>($($arg),*),
+            23 => $f::<day23::Day23>($($arg),*),
+            24 => $f::<day24::Day24>($($arg),*),
+            25 => $f::<day25::Day25>($($arg),*),
+            day => panic!("There is no day {}!", day),
+        }
+    };
+}
+
+pub fn run(
+    day: u8,
+    input: &str,
+    parts: &[u8],
+    params: &[(String, String)],
+) -> Result<Vec<Solution>, SolveError> {
+    dispatch!(day, solve(input, parts, params))
+}
+
+pub fn run_bench(day: u8, input: &str, runs: usize) -> Result<Vec<Phase>, SolveError> {
+    dispatch!(day, bench(input, runs))
+}
+
+pub fn run_generate(
+    day: u8,
+    seed: u64,
+    size: Option<usize>,
+    params: &[(String, String)],
+) -> Result<String, SolveError> {
+    dispatch!(day, generate(seed, size, params))
+}
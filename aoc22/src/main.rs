@@ -0,0 +1,326 @@
+use aoc_common::trace::{self, TraceTarget};
+use aoc_common::{param_pair, InputSource, SolveError};
+use clap::{Parser, Subcommand};
+use report::Format;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+mod alloc;
+mod bench;
+mod days;
+mod pool;
+mod report;
+mod verify;
+
+#[global_allocator]
+static ALLOCATOR: alloc::Counting = alloc::Counting;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or all of them
+    Run {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=days::DAYS as i64))]
+        day: Option<u8>,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Path of the input, or - for stdin [default: dayNN/data/input.txt]
+        #[arg(long, requires = "day")]
+        input: Option<InputSource>,
+        /// Override a puzzle parameter, e.g. --param rounds=20
+        #[arg(long, value_name = "NAME=VALUE", value_parser = param_pair, requires = "day")]
+        param: Vec<(String, String)>,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Number of threads solving days, and both parts of a day, concurrently
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+        jobs: u64,
+        /// Record the frames of the simulations: term, log:<path>, ppm:<dir> or png:<dir>
+        #[arg(long, value_name = "TARGET", requires = "day")]
+        trace: Option<TraceTarget>,
+        /// Only keep one frame out of N
+        #[arg(long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+        trace_every: u64,
+        /// Report the heap used by each part: peak, number and bytes of the allocations
+        #[arg(long)]
+        memory: bool,
+    },
+    /// Time parsing and both parts of one day, or all of them
+    Bench {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=days::DAYS as i64))]
+        day: Option<u8>,
+        /// Number of timed runs of each phase
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        runs: u64,
+        /// Where to write the results
+        #[arg(long, default_value = "bench_output.txt")]
+        output: PathBuf,
+        /// Previous results to compare the medians with
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+    },
+    /// Check the answers of one day, or all of them, against the recorded ones
+    Verify {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=days::DAYS as i64))]
+        day: Option<u8>,
+        /// [default: answers.toml at the root of the workspace]
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Print a random input for one day, e.g. to pipe into run --input -
+    Generate {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=days::DAYS as i64))]
+        day: u8,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Day-specific count, e.g. of lines [default: about the size of the puzzle input]
+        #[arg(long)]
+        size: Option<usize>,
+        /// Puzzle parameter the input must be solvable with, e.g. --param maxcoord=20
+        #[arg(long, value_name = "NAME=VALUE", value_parser = param_pair)]
+        param: Vec<(String, String)>,
+    },
+}
+
+fn selected_days(day: Option<u8>) -> Vec<u8> {
+    match day {
+        Some(day) => vec![day],
+        None => (1..=days::DAYS).collect(),
+    }
+}
+
+// Default paths do not depend on the current directory.
+fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+fn input_path(day: u8) -> InputSource {
+    InputSource::File(workspace_dir().join(format!("day{:02}/data/input.txt", day)))
+}
+
+fn read_input(source: &InputSource) -> Result<String, String> {
+    source
+        .read()
+        .map_err(|err| format!("Cannot read {}: {}", source, err))
+}
+
+fn run(
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<&InputSource>,
+    params: &[(String, String)],
+    format: Format,
+    jobs: usize,
+) -> ExitCode {
+    let parts: Vec<u8> = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let days = selected_days(day);
+    let inputs: Vec<(InputSource, Result<String, String>)> = days
+        .iter()
+        .map(|&day| {
+            let path = input.cloned().unwrap_or_else(|| input_path(day));
+            let text = read_input(&path);
+            (path, text)
+        })
+        .collect();
+    // Tasks are indexes in `days` and the parts to solve, each part apart when
+    // there are several threads.
+    let tasks: Vec<(usize, Vec<u8>)> = (0..days.len())
+        .flat_map(|index| match jobs {
+            1 => vec![(index, parts.clone())],
+            _ => parts.iter().map(|&part| (index, vec![part])).collect(),
+        })
+        .collect();
+    let solve = |(index, parts): &(usize, Vec<u8>)| {
+        let (path, text) = &inputs[*index];
+        let text = text.as_ref().map_err(|message| message.clone())?;
+        days::run(days[*index], text, parts, params).map_err(|err| format!("{}: {}", path, err))
+    };
+    let mut status = ExitCode::SUCCESS;
+    let (mut started, mut failed) = (None, None);
+    pool::for_each(&tasks, jobs, solve, |&(index, _), solutions| {
+        let day = days[index];
+        if started != Some(index) {
+            report::day_header(format, day);
+            started = Some(index);
+        }
+        let solutions = match solutions {
+            Ok(solutions) => solutions,
+            Err(message) => {
+                // Both parts fail the same when solved apart.
+                if failed != Some(index) {
+                    report::failure(format, day, &parts, &message);
+                    failed = Some(index);
+                }
+                status = ExitCode::FAILURE;
+                return;
+            }
+        };
+        for solution in solutions {
+            match solution.answer {
+                // Only report the missing part of day 25 when asked for it
+                Err(SolveError::NoSuchPart) if part.is_none() => continue,
+                Err(_) => status = ExitCode::FAILURE,
+                Ok(_) => (),
+            }
+            report::solution(format, day, &solution);
+        }
+    });
+    status
+}
+
+fn verify(day: Option<u8>, answers: &Path) -> ExitCode {
+    let manifest = match verify::load(answers) {
+        Ok(manifest) => manifest,
+        Err(err) => {
+            eprintln!("Cannot read {}: {}", answers.display(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+    verify::print_header();
+    let mut failures = 0;
+    for day in selected_days(day) {
+        let path = input_path(day);
+        let solutions = read_input(&path).and_then(|text| {
+            days::run(day, &text, &[1, 2], &[]).map_err(|err| format!("{}: {}", path, err))
+        });
+        let solutions = match solutions {
+            Ok(solutions) => solutions,
+            Err(message) => {
+                eprintln!("{}", message);
+                failures += 1;
+                continue;
+            }
+        };
+        for solution in solutions {
+            if let Err(SolveError::NoSuchPart) = solution.answer {
+                continue;
+            }
+            let expected = verify::expected(&manifest, day, solution.part);
+            let status = verify::check(expected, &solution.answer);
+            if status.is_failure() {
+                failures += 1;
+            }
+            verify::print_row(day, &solution, expected, &status);
+        }
+    }
+    if failures > 0 {
+        println!("\n{} failure(s)", failures);
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn bench(day: Option<u8>, runs: usize, output: &Path, baseline: Option<&Path>) -> ExitCode {
+    let baseline = match baseline.map(bench::load).transpose() {
+        Ok(baseline) => baseline,
+        Err(err) => {
+            eprintln!("Cannot read the baseline: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut status = ExitCode::SUCCESS;
+    let mut results = Vec::new();
+    for day in selected_days(day) {
+        let path = input_path(day);
+        let text = match read_input(&path) {
+            Ok(text) => text,
+            Err(message) => {
+                eprintln!("{}", message);
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        match days::run_bench(day, &text, runs) {
+            Ok(phases) => {
+                for phase in &phases {
+                    bench::print(day, phase, baseline.as_ref());
+                }
+                results.push((day, phases));
+            }
+            Err(err) => {
+                eprintln!("{}: {}", path, err);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    if let Err(err) = bench::save(output, &results) {
+        eprintln!("Cannot write {}: {}", output.display(), err);
+        status = ExitCode::FAILURE;
+    }
+    status
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let mut status = ExitCode::SUCCESS;
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            param,
+            format,
+            jobs,
+            trace,
+            trace_every,
+            memory,
+        } => {
+            // The counts and the frames are shared by all the threads.
+            if memory && jobs > 1 {
+                eprintln!("error: --memory cannot be used with more than one job");
+                return ExitCode::FAILURE;
+            }
+            if trace.is_some() && jobs > 1 {
+                eprintln!("error: --trace cannot be used with more than one job");
+                return ExitCode::FAILURE;
+            }
+            if memory {
+                alloc::enable();
+            }
+            if let Some(target) = &trace {
+                if let Err(err) = trace::start(target, trace_every as usize) {
+                    eprintln!("Cannot trace to {}: {}", target, err);
+                    return ExitCode::FAILURE;
+                }
+            }
+            status = run(day, part, input.as_ref(), &param, format, jobs as usize);
+            if let Err(err) = trace::stop() {
+                eprintln!("Cannot write the trace: {}", err);
+                status = ExitCode::FAILURE;
+            }
+        }
+        Command::Bench {
+            day,
+            runs,
+            output,
+            baseline,
+        } => status = bench(day, runs as usize, &output, baseline.as_deref()),
+        Command::Verify { day, answers } => {
+            let answers = answers.unwrap_or_else(|| workspace_dir().join("answers.toml"));
+            status = verify(day, &answers)
+        }
+        Command::Generate {
+            day,
+            seed,
+            size,
+            param,
+        } => match days::run_generate(day, seed, size, &param) {
+            Ok(input) => print!("{}", input),
+            Err(err) => {
+                eprintln!("{}", err);
+                status = ExitCode::FAILURE;
+            }
+        },
+    }
+    status
+}
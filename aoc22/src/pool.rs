@@ -0,0 +1,40 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+// Call `job` on every item from up to `threads` threads, each taking the next
+// item when done with one. `done` gets the results in the order of the items,
+// as soon as the previous ones are available.
+pub fn for_each<T, R, F, D>(items: &[T], threads: usize, job: F, mut done: D)
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+    D: FnMut(&T, R),
+{
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, items.len().max(1)) {
+            let (next, job, sender) = (&next, &job, sender.clone());
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                match items.get(index) {
+                    Some(item) => sender.send((index, job(item))).unwrap(),
+                    None => break,
+                }
+            });
+        }
+        drop(sender);
+        let mut pending = BTreeMap::new();
+        let mut first = 0;
+        for (index, result) in receiver {
+            pending.insert(index, result);
+            while let Some(result) = pending.remove(&first) {
+                done(&items[first], result);
+                first += 1;
+            }
+        }
+    });
+}
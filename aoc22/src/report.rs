@@ -0,0 +1,127 @@
+use crate::alloc::Usage;
+use crate::days::Solution;
+use aoc_common::{print_answer, Answer};
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human-readable answers, grouped by day
+    Text,
+    /// One JSON record per line: {day, part, answer, elapsed_ns}, and memory with --memory
+    Json,
+}
+
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    answer: Value,
+    elapsed_ns: u128,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    memory: Option<Usage>,
+}
+
+// Integers stay numbers, rasters become a single multi-line string.
+fn answer_value(answer: &Answer) -> Value {
+    match answer {
+        Answer::Int(val) => Value::from(*val),
+        other => Value::from(other.to_string()),
+    }
+}
+
+fn print_record(
+    day: u8,
+    part: u8,
+    answer: Value,
+    elapsed_ns: u128,
+    error: Option<&str>,
+    memory: Option<Usage>,
+) {
+    let record = Record {
+        day,
+        part,
+        answer,
+        elapsed_ns,
+        error,
+        memory,
+    };
+    println!("{}", serde_json::to_string(&record).unwrap());
+}
+
+// A number of bytes in the largest binary unit it has at least one of.
+fn bytes(count: usize) -> String {
+    let units = ["B", "KiB", "MiB", "GiB"];
+    let mut value = count as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < units.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    match unit {
+        0 => format!("{} B", count),
+        _ => format!("{:.1} {}", value, units[unit]),
+    }
+}
+
+pub fn day_header(format: Format, day: u8) {
+    if format == Format::Text {
+        println!("\n## day{:02}:", day);
+    }
+}
+
+pub fn solution(format: Format, day: u8, solution: &Solution) {
+    match format {
+        Format::Text => {
+            print_answer(solution.part, &solution.answer);
+            if let Some(memory) = &solution.memory {
+                println!(
+                    "        heap: input {}, peak {}, {} allocations of {}",
+                    bytes(memory.input),
+                    bytes(memory.peak),
+                    memory.allocations,
+                    bytes(memory.allocated)
+                );
+            }
+        }
+        Format::Json => {
+            let elapsed_ns = solution.elapsed.as_nanos();
+            match &solution.answer {
+                Ok(answer) => print_record(
+                    day,
+                    solution.part,
+                    answer_value(answer),
+                    elapsed_ns,
+                    None,
+                    solution.memory,
+                ),
+                Err(err) => {
+                    let error = err.to_string();
+                    print_record(
+                        day,
+                        solution.part,
+                        Value::Null,
+                        elapsed_ns,
+                        Some(&error),
+                        solution.memory,
+                    );
+                }
+            }
+        }
+    }
+}
+
+// The input of a day could not be read or parsed, so every requested part failed.
+pub fn failure(format: Format, day: u8, parts: &[u8], message: &str) {
+    match format {
+        Format::Text => eprintln!("{}", message),
+        Format::Json => {
+            for &part in parts {
+                print_record(day, part, Value::Null, 0, Some(message), None);
+            }
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use crate::days::Solution;
+use aoc_common::{Answer, SolveError};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs::read_to_string;
+use std::path::Path;
+
+#[derive(Deserialize)]
+pub struct Expected {
+    answer: toml::Value,
+    // Answers that were submitted and rejected.
+    #[serde(default)]
+    wrong: Vec<toml::Value>,
+}
+
+// Indexed by "dayNN" then "partN".
+pub type Manifest = BTreeMap<String, BTreeMap<String, Expected>>;
+
+pub fn load(path: &Path) -> Result<Manifest, String> {
+    let text = read_to_string(path).map_err(|err| err.to_string())?;
+    toml::from_str(&text).map_err(|err| err.to_string())
+}
+
+pub fn expected(manifest: &Manifest, day: u8, part: u8) -> Option<&Expected> {
+    manifest
+        .get(&format!("day{:02}", day))?
+        .get(&format!("part{}", part))
+}
+
+// Rasters are compared row by row, ignoring trailing spaces.
+fn normalize(text: &str) -> String {
+    let rows: Vec<&str> = text.lines().map(str::trim_end).collect();
+    rows.join("\n").trim_matches('\n').to_string()
+}
+
+fn value_to_string(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(text) => normalize(text),
+        other => other.to_string(),
+    }
+}
+
+// Single-line version of an answer for the table.
+fn summary(text: &str) -> String {
+    match text.lines().count() {
+        0 | 1 => text.to_string(),
+        rows => format!("<{} rows>", rows),
+    }
+}
+
+#[derive(PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    // The answer was already submitted and rejected.
+    KnownWrong,
+    Mismatch,
+    Error,
+    Unrecorded,
+}
+
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Status::Ok | Status::Unrecorded)
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::KnownWrong => "WRONG",
+            Status::Mismatch => "FAIL",
+            Status::Error => "ERROR",
+            Status::Unrecorded => "new",
+        }
+    }
+}
+
+pub fn check(expected: Option<&Expected>, answer: &Result<Answer, SolveError>) -> Status {
+    let answer = match answer {
+        Ok(answer) => normalize(&answer.to_string()),
+        Err(_) => return Status::Error,
+    };
+    match expected {
+        None => Status::Unrecorded,
+        Some(expected) if value_to_string(&expected.answer) == answer => Status::Ok,
+        Some(expected) if expected.wrong.iter().any(|w| value_to_string(w) == answer) => {
+            Status::KnownWrong
+        }
+        Some(_) => Status::Mismatch,
+    }
+}
+
+pub fn print_header() {
+    println!(
+        "{:<6}{:<6}{:<7}{:<24}expected",
+        "day", "part", "status", "answer"
+    );
+}
+
+pub fn print_row(day: u8, solution: &Solution, expected: Option<&Expected>, status: &Status) {
+    let got = match &solution.answer {
+        Ok(answer) => summary(&answer.to_string()),
+        Err(err) => err.to_string(),
+    };
+    let expected = match (status, expected) {
+        (Status::Ok, _) | (_, None) => String::new(),
+        (_, Some(expected)) => summary(&value_to_string(&expected.answer)),
+    };
+    let row = format!(
+        "day{:02} {:<6}{:<7}{:<24}{}",
+        day,
+        solution.part,
+        status.label(),
+        got,
+        expected
+    );
+    println!("{}", row.trim_end());
+}
@@ -0,0 +1,20 @@
+use crate::Day01;
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// `size` elves, at least 3, carrying a few snacks each.
+impl Generator for Day01 {
+    const SIZE: usize = 250;
+
+    fn generate(rng: &mut StdRng, size: usize, _params: &Self::Params) -> String {
+        let elves: Vec<String> = (0..size.max(3))
+            .map(|_| {
+                (0..rng.gen_range(1..=15))
+                    .map(|_| format!("{}\n", rng.gen_range(1000..=70000)))
+                    .collect()
+            })
+            .collect();
+        elves.join("\n")
+    }
+}
@@ -0,0 +1,185 @@
+use aoc_common::{params, parse_token, Answer, SolveError, Solver};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+mod generate;
+
+/// The calories carried by each elf, in order, summed as the inventory is
+/// read, one line at a time.
+pub fn elves(input: impl BufRead) -> impl Iterator<Item = Result<u64, SolveError>> {
+    let mut lines = input.lines().enumerate().peekable();
+    std::iter::from_fn(move || {
+        lines.peek()?;
+        let mut calories = 0;
+        for (n, line) in lines.by_ref() {
+            let line = match line {
+                Ok(line) => line,
+                Err(err) => {
+                    return Some(Err(SolveError::Parse {
+                        line: n + 1,
+                        column: 1,
+                        message: err.to_string(),
+                    }))
+                }
+            };
+            // Elves are separated by an empty line.
+            if line.is_empty() {
+                break;
+            }
+            match parse_token::<u64>(&line, &line) {
+                Ok(snack) => calories += snack,
+                Err(err) => return Some(Err(err.after_lines(n))),
+            }
+        }
+        Some(Ok(calories))
+    })
+}
+
+// The calories of each elf of an inventory already checked by `parse`.
+fn calories(input: &str) -> impl Iterator<Item = u64> + '_ {
+    elves(input.as_bytes()).flatten()
+}
+
+/// The `n` largest calories, from the largest, only keeping `n` of them at a time.
+pub fn top_n(elves: impl IntoIterator<Item = u64>, n: usize) -> Vec<u64> {
+    let mut top = BinaryHeap::with_capacity(n + 1);
+    for calories in elves {
+        top.push(Reverse(calories));
+        if top.len() > n {
+            top.pop();
+        }
+    }
+    top.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(calories)| calories)
+        .collect()
+}
+
+/// Rank of an elf carrying `calories`, 1 being the elf carrying the most.
+/// Elves carrying as much share the same rank.
+pub fn rank(elves: impl IntoIterator<Item = u64>, calories: u64) -> usize {
+    1 + elves.into_iter().filter(|&other| other > calories).count()
+}
+
+/// The calories that `percent` of the elves carry at most, with the nearest
+/// rank method. None without elves. Unlike the other queries, this one keeps
+/// the calories of all the elves, so it needs memory for as many.
+pub fn percentile(elves: impl IntoIterator<Item = u64>, percent: f64) -> Option<u64> {
+    let mut elves: Vec<u64> = elves.into_iter().collect();
+    if elves.is_empty() {
+        return None;
+    }
+    let rank = (percent.clamp(0.0, 100.0) / 100.0 * elves.len() as f64).ceil() as usize;
+    let (_, calories, _) = elves.select_nth_unstable(rank.max(1) - 1);
+    Some(*calories)
+}
+
+/// The elves carrying more than `threshold`, as their index and calories.
+pub fn above(
+    elves: impl IntoIterator<Item = u64>,
+    threshold: u64,
+) -> impl Iterator<Item = (usize, u64)> {
+    elves
+        .into_iter()
+        .enumerate()
+        .filter(move |&(_, calories)| calories > threshold)
+}
+
+params! {
+    pub struct Params {
+        // Number of elves whose calories are summed in part 2.
+        top: usize = 3,
+    }
+}
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    // The inventory itself, only checked here and read again by each part.
+    type Input = String;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        elves(input.as_bytes()).try_for_each(|elf| elf.map(drop))?;
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        match top_n(calories(input), 1).first() {
+            Some(max) => Ok((*max).into()),
+            None => Err(SolveError::no_solution("there is no elf")),
+        }
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Result<Answer, SolveError> {
+        let top = top_n(calories(input), params.top);
+        if top.len() < params.top {
+            let message = format!("there are less than {} elves", params.top);
+            return Err(SolveError::no_solution(message));
+        }
+        Ok(top.iter().sum::<u64>().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_generated};
+    use std::fs::{read_to_string, File};
+    use std::io::BufReader;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        solve::<Day01>(&read_to_string("./data/example.txt").unwrap(), part)
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(1), Ok(Answer::Int(24000)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(2), Ok(Answer::Int(45000)));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        let solved = solve_generated::<Day01>(0..10, 20, &Params::default(), &[1, 2]);
+        for (input, answers) in solved {
+            let mut elves: Vec<u64> = input
+                .trim_end()
+                .split("\n\n")
+                .map(|elf| elf.lines().map(|line| line.parse::<u64>().unwrap()).sum())
+                .collect();
+            elves.sort_unstable_by(|a, b| b.cmp(a));
+            let expected = [elves[0], elves[..3].iter().sum()].map(Answer::from);
+            assert_eq!(answers, expected);
+        }
+    }
+
+    #[test]
+    fn queries() {
+        let file = BufReader::new(File::open("./data/example.txt").unwrap());
+        let elves: Vec<u64> = elves(file).collect::<Result<_, _>>().unwrap();
+        assert_eq!(elves, [6000, 4000, 11000, 24000, 10000]);
+        assert_eq!(top_n(elves.iter().copied(), 2), [24000, 11000]);
+        assert_eq!(top_n(elves.iter().copied(), 9).len(), 5);
+        assert_eq!(rank(elves.iter().copied(), 10000), 3);
+        assert_eq!(percentile(elves.iter().copied(), 50.0), Some(10000));
+        assert_eq!(percentile(elves.iter().copied(), 100.0), Some(24000));
+        assert_eq!(percentile([], 50.0), None);
+        let above: Vec<_> = above(elves.iter().copied(), 10000).collect();
+        assert_eq!(above, [(2, 11000), (3, 24000)]);
+    }
+
+    #[test]
+    fn parse_error() {
+        let mut elves = elves("1000\n2000\n\n3000\nlots\n".as_bytes());
+        assert_eq!(elves.next(), Some(Ok(3000)));
+        assert_eq!(
+            elves.next().unwrap().err().unwrap().to_string(),
+            "line 5, column 1: expected u64, found 'lots'"
+        );
+    }
+}
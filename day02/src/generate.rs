@@ -0,0 +1,19 @@
+use crate::Day02;
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// `size` rounds.
+impl Generator for Day02 {
+    const SIZE: usize = 2500;
+
+    fn generate(rng: &mut StdRng, size: usize, _params: &Self::Params) -> String {
+        (0..size)
+            .map(|_| {
+                let opponent = ['A', 'B', 'C'][rng.gen_range(0..3)];
+                let player = ['X', 'Y', 'Z'][rng.gen_range(0..3)];
+                format!("{} {}\n", opponent, player)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,137 @@
+use aoc_common::{next_token, parse_lines, Answer, SolveError, Solver};
+
+mod generate;
+mod rules;
+
+pub use rules::{best_mapping, evaluate, rounds, Game, Outcome, Round, Scoring, Strategy, Tally};
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    // Rounds of the opponent's shape and the index of the second letter.
+    type Input = Vec<(usize, usize)>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_lines(input, |line| {
+            let mut letters = line.split_ascii_whitespace();
+            let opponent = next_token(line, letters.next(), "a letter among ABC")?;
+            let player = next_token(line, letters.next(), "a letter among XYZ")?;
+            Ok((letter(line, opponent, "ABC")?, letter(line, player, "XYZ")?))
+        })
+    }
+
+    fn part1(rounds: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let game = Game::rock_paper_scissors();
+        let tally = evaluate(&game, rounds, &Strategy::shapes(&game))?;
+        Ok(tally.score.into())
+    }
+
+    fn part2(rounds: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let game = Game::rock_paper_scissors();
+        let tally = evaluate(&game, rounds, &Strategy::Outcomes)?;
+        Ok(tally.score.into())
+    }
+}
+
+fn letter(line: &str, token: &str, letters: &str) -> Result<usize, SolveError> {
+    match token.chars().next() {
+        Some(c) if token.len() == 1 && letters.contains(c) => Ok(letters.find(c).unwrap()),
+        _ => Err(SolveError::at(
+            line,
+            token,
+            format!("expected a letter among {}, found '{}'", letters, token),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_generated};
+    use std::fs::read_to_string;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        solve::<Day02>(&read_to_string("./data/example.txt").unwrap(), part)
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(1), Ok(Answer::Int(15)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(2), Ok(Answer::Int(12)));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        // Against the scores of the 9 possible rounds, worked out by hand.
+        let rounds = [
+            "A X", "A Y", "A Z", "B X", "B Y", "B Z", "C X", "C Y", "C Z",
+        ];
+        let score = |input: &str, scores: [i32; 9]| {
+            let round = |line| rounds.iter().position(|round| *round == line).unwrap();
+            Answer::from(input.lines().map(|line| scores[round(line)]).sum::<i32>())
+        };
+        for (input, answers) in solve_generated::<Day02>(0..10, 100, &(), &[1, 2]) {
+            let part1 = score(&input, [4, 8, 3, 1, 5, 9, 7, 2, 6]);
+            let part2 = score(&input, [3, 4, 8, 1, 5, 9, 2, 6, 7]);
+            assert_eq!(answers, [part1, part2]);
+        }
+    }
+
+    #[test]
+    fn rock_paper_scissors_lizard_spock() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let shape = |name| (0..game.len()).find(|&s| game.name(s) == name).unwrap();
+        let wins = [
+            ("Scissors", "Paper"),
+            ("Paper", "Rock"),
+            ("Rock", "Lizard"),
+            ("Lizard", "Spock"),
+            ("Spock", "Scissors"),
+            ("Scissors", "Lizard"),
+            ("Lizard", "Paper"),
+            ("Paper", "Spock"),
+            ("Spock", "Rock"),
+            ("Rock", "Scissors"),
+        ];
+        for (winner, loser) in wins {
+            assert_eq!(game.outcome(shape(winner), shape(loser)), Outcome::Win);
+            assert_eq!(game.outcome(shape(loser), shape(winner)), Outcome::Loss);
+        }
+        assert_eq!(
+            game.choose(shape("Rock"), Outcome::Win),
+            Some(shape("Paper"))
+        );
+        assert!(Game::cyclic(&["A", "B", "C", "D"]).is_err());
+    }
+
+    #[test]
+    fn strategies() {
+        let game = Game::rock_paper_scissors();
+        let guide = Day02::parse(&read_to_string("./data/example.txt").unwrap()).unwrap();
+        let tally = evaluate(&game, &guide, &Strategy::shapes(&game)).unwrap();
+        let expected = Tally {
+            score: 15,
+            wins: 1,
+            draws: 1,
+            losses: 1,
+        };
+        assert_eq!(tally, expected);
+        let tally = evaluate(&game, &guide, &Strategy::Outcomes).unwrap();
+        assert_eq!((tally.score, tally.wins, tally.draws), (12, 1, 1));
+    }
+
+    #[test]
+    fn best_mapping_example() {
+        let game = Game::rock_paper_scissors();
+        let guide = Day02::parse(&read_to_string("./data/example.txt").unwrap()).unwrap();
+        let (strategy, tally) = best_mapping(&game, &guide).unwrap();
+        // Every round is won with X as Scissors, Y as Paper and Z as Rock.
+        assert_eq!(strategy, Strategy::Shapes(vec![2, 1, 0]));
+        assert_eq!((tally.score, tally.wins), (24, 3));
+    }
+}
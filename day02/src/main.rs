@@ -1,86 +1,64 @@
-use std::error::Error;
+use aoc_common::Solver;
+use day02::{best_mapping, rounds, Day02, Game, Outcome, Strategy};
 use std::fs::read_to_string;
+use std::iter::zip;
+use std::process::ExitCode;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    // Read input
-    let input = read_to_string("./data/input.txt")?;
+const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data/input.txt");
 
-    // Part 1
-    let score: i32 = input.lines().map(|line| play1(line)).sum();
-    println!("Part 1: {:?}", score);
-
-    // Part 2
-    let score: i32 = input.lines().map(|line| play2(line)).sum();
-    println!("Part 2: {:?}", score);
-
-    Ok(())
-}
-
-fn play1(line: &str) -> i32 {
-    let mut shapes = line.split_ascii_whitespace();
-    let opponent = Shape::new(shapes.next().unwrap());
-    let player = Shape::new(shapes.next().unwrap());
-    player.play(&opponent)
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("best") => best(args.get(1).map_or(INPUT, String::as_str)),
+        _ => aoc_common::run::<Day02>(INPUT),
+    }
 }
 
-fn play2(line: &str) -> i32 {
-    let mut shapes = line.split_ascii_whitespace();
-    let opponent = Shape::new(shapes.next().unwrap());
-    let expected = shapes.next().unwrap();
-    let player = match (expected, &opponent) {
-        ("X", Shape::Rock) => Shape::Scissor,
-        ("X", Shape::Paper) => Shape::Rock,
-        ("X", Shape::Scissor) => Shape::Paper,
-        ("Y", Shape::Rock) => Shape::Rock,
-        ("Y", Shape::Paper) => Shape::Paper,
-        ("Y", Shape::Scissor) => Shape::Scissor,
-        ("Z", Shape::Rock) => Shape::Paper,
-        ("Z", Shape::Paper) => Shape::Scissor,
-        ("Z", Shape::Scissor) => Shape::Rock,
-        _ => panic!("Unexpected line!"),
+// Find the reading of XYZ as shapes scoring the most over the guide at `path`,
+// and show how each round goes with it.
+fn best(path: &str) -> ExitCode {
+    let guide = match read_to_string(path) {
+        Ok(input) => Day02::parse(&input),
+        Err(err) => {
+            eprintln!("Cannot read {}: {}", path, err);
+            return ExitCode::FAILURE;
+        }
     };
-    player.play(&opponent)
-}
-
-enum Shape {
-    Rock,
-    Paper,
-    Scissor,
-}
-
-impl Shape {
-    fn new(letter: &str) -> Self {
-        match letter {
-            "A" => Self::Rock,
-            "B" => Self::Paper,
-            "C" => Self::Scissor,
-            "X" => Self::Rock,
-            "Y" => Self::Paper,
-            "Z" => Self::Scissor,
-            _ => panic!("Unsuported letter!"),
+    let game = Game::rock_paper_scissors();
+    let result = guide.and_then(|guide| {
+        let (strategy, tally) = best_mapping(&game, &guide)?;
+        Ok((rounds(&game, &guide, &strategy)?, strategy, tally))
+    });
+    let (rounds, strategy, tally) = match result {
+        Ok(result) => result,
+        Err(err) => {
+            eprintln!("{}: {}", path, err);
+            return ExitCode::FAILURE;
         }
-    }
-
-    fn value(&self) -> i32 {
-        match self {
-            Self::Rock => 1,
-            Self::Paper => 2,
-            Self::Scissor => 3,
+    };
+    if let Strategy::Shapes(mapping) = &strategy {
+        for (letter, &shape) in zip(["X", "Y", "Z"], mapping) {
+            println!("{} = {}", letter, game.name(shape));
         }
     }
-
-    fn play(&self, opponent: &Shape) -> i32 {
-        let won = match (self, opponent) {
-            (Self::Rock, Self::Rock) => 3,
-            (Self::Rock, Self::Paper) => 0,
-            (Self::Rock, Self::Scissor) => 6,
-            (Self::Paper, Self::Paper) => 3,
-            (Self::Paper, Self::Scissor) => 0,
-            (Self::Paper, Self::Rock) => 6,
-            (Self::Scissor, Self::Rock) => 0,
-            (Self::Scissor, Self::Paper) => 6,
-            (Self::Scissor, Self::Scissor) => 3,
+    for (number, round) in rounds.iter().enumerate() {
+        let outcome = match round.outcome {
+            Outcome::Loss => "loss",
+            Outcome::Draw => "draw",
+            Outcome::Win => "win",
         };
-        won + self.value()
+        println!(
+            "{:>5}: {:<8} vs {:<8} {:<4} {}",
+            number + 1,
+            game.name(round.player),
+            game.name(round.opponent),
+            outcome,
+            round.score
+        );
     }
+    println!(
+        "score {}: {} wins, {} draws, {} losses",
+        tally.score, tally.wins, tally.draws, tally.losses
+    );
+    ExitCode::SUCCESS
 }
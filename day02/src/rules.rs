@@ -0,0 +1,280 @@
+use aoc_common::SolveError;
+use std::iter::zip;
+
+/// Result of a round for the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+/// Points for each outcome of a round, added to the value of the player's shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Scoring {
+    pub loss: i32,
+    pub draw: i32,
+    pub win: i32,
+}
+
+impl Default for Scoring {
+    fn default() -> Self {
+        Self {
+            loss: 0,
+            draw: 3,
+            win: 6,
+        }
+    }
+}
+
+/// A game of shapes, given as data: their names and values, which shape beats
+/// which and how rounds are scored. Shapes are referred to by their index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    names: Vec<String>,
+    values: Vec<i32>,
+    // beats[a][b]: shape a beats shape b.
+    beats: Vec<Vec<bool>>,
+    scoring: Scoring,
+}
+
+impl Game {
+    /// A game where shape `a` beats shape `b` when `beats(a, b)`, shapes being
+    /// worth 1, 2, 3... in order. Two shapes cannot beat each other.
+    pub fn new(
+        names: &[&str],
+        beats: impl Fn(usize, usize) -> bool,
+        scoring: Scoring,
+    ) -> Result<Self, SolveError> {
+        let n = names.len();
+        let beats: Vec<Vec<bool>> = (0..n)
+            .map(|a| (0..n).map(|b| a != b && beats(a, b)).collect())
+            .collect();
+        for a in 0..n {
+            for b in 0..n {
+                if beats[a][b] && beats[b][a] {
+                    return Err(SolveError::Param(format!(
+                        "{} and {} cannot beat each other",
+                        names[a], names[b]
+                    )));
+                }
+            }
+        }
+        Ok(Self {
+            names: names.iter().map(|name| name.to_string()).collect(),
+            values: (1..=n as i32).collect(),
+            beats,
+            scoring,
+        })
+    }
+
+    /// A balanced game with an odd number of shapes, each beating the half of
+    /// the others just before it, cyclically.
+    pub fn cyclic(names: &[&str]) -> Result<Self, SolveError> {
+        let n = names.len();
+        if n.is_multiple_of(2) {
+            return Err(SolveError::Param(format!(
+                "a cyclic game needs an odd number of shapes, found {}",
+                n
+            )));
+        }
+        Self::new(names, |a, b| (a + n - b) % n <= n / 2, Scoring::default())
+    }
+
+    pub fn rock_paper_scissors() -> Self {
+        Self::cyclic(&["Rock", "Paper", "Scissors"]).unwrap()
+    }
+
+    /// Ordered so that each shape beats the two before it.
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Self::cyclic(&["Rock", "Spock", "Paper", "Lizard", "Scissors"]).unwrap()
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn name(&self, shape: usize) -> &str {
+        &self.names[shape]
+    }
+
+    pub fn outcome(&self, player: usize, opponent: usize) -> Outcome {
+        if self.beats[player][opponent] {
+            Outcome::Win
+        } else if self.beats[opponent][player] {
+            Outcome::Loss
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    pub fn score(&self, player: usize, opponent: usize) -> i32 {
+        let points = match self.outcome(player, opponent) {
+            Outcome::Loss => self.scoring.loss,
+            Outcome::Draw => self.scoring.draw,
+            Outcome::Win => self.scoring.win,
+        };
+        points + self.values[player]
+    }
+
+    /// The best scoring shape to play against `opponent` for the `outcome`.
+    pub fn choose(&self, opponent: usize, outcome: Outcome) -> Option<usize> {
+        (0..self.len())
+            .filter(|&player| self.outcome(player, opponent) == outcome)
+            .max_by_key(|&player| self.score(player, opponent))
+    }
+}
+
+/// How the second letter of a round of the guide is read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Strategy {
+    /// The shape to play: letter `i` is shape `shapes[i]`.
+    Shapes(Vec<usize>),
+    /// The outcome to get: letters are a loss, a draw and a win.
+    Outcomes,
+}
+
+impl Strategy {
+    /// Letter `i` is shape `i`.
+    pub fn shapes(game: &Game) -> Self {
+        Strategy::Shapes((0..game.len()).collect())
+    }
+
+    /// The shape played against `opponent` for the `letter`, None if the letter
+    /// has no meaning or the outcome cannot be reached.
+    pub fn decode(&self, game: &Game, opponent: usize, letter: usize) -> Option<usize> {
+        match self {
+            Strategy::Shapes(shapes) => shapes.get(letter).copied(),
+            Strategy::Outcomes => {
+                let outcome = [Outcome::Loss, Outcome::Draw, Outcome::Win].get(letter)?;
+                game.choose(opponent, *outcome)
+            }
+        }
+    }
+}
+
+/// Total score and outcomes of the rounds of a guide.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Tally {
+    pub score: i32,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+}
+
+impl Tally {
+    pub fn add(&mut self, round: Round) {
+        self.score += round.score;
+        match round.outcome {
+            Outcome::Loss => self.losses += 1,
+            Outcome::Draw => self.draws += 1,
+            Outcome::Win => self.wins += 1,
+        }
+    }
+}
+
+/// A round of a guide as played with some strategy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: usize,
+    pub letter: usize,
+    pub player: usize,
+    pub outcome: Outcome,
+    pub score: i32,
+}
+
+/// Play the `guide`, rounds of the opponent's shape and a letter, with the
+/// `strategy`, round by round.
+pub fn rounds(
+    game: &Game,
+    guide: &[(usize, usize)],
+    strategy: &Strategy,
+) -> Result<Vec<Round>, SolveError> {
+    guide
+        .iter()
+        .map(|&(opponent, letter)| {
+            if opponent >= game.len() {
+                let message = format!("the game has no shape {}", opponent);
+                return Err(SolveError::no_solution(message));
+            }
+            match strategy.decode(game, opponent, letter) {
+                Some(player) if player < game.len() => Ok(Round {
+                    opponent,
+                    letter,
+                    player,
+                    outcome: game.outcome(player, opponent),
+                    score: game.score(player, opponent),
+                }),
+                _ => {
+                    let message = format!("letter {} has no meaning with {:?}", letter, strategy);
+                    Err(SolveError::no_solution(message))
+                }
+            }
+        })
+        .collect()
+}
+
+/// Play the `guide` with the `strategy`.
+pub fn evaluate(
+    game: &Game,
+    guide: &[(usize, usize)],
+    strategy: &Strategy,
+) -> Result<Tally, SolveError> {
+    let mut tally = Tally::default();
+    for round in rounds(game, guide, strategy)? {
+        tally.add(round);
+    }
+    Ok(tally)
+}
+
+/// The mapping of letters to shapes scoring the most over the `guide`, the
+/// first one in lexicographic order on ties.
+pub fn best_mapping(
+    game: &Game,
+    guide: &[(usize, usize)],
+) -> Result<(Strategy, Tally), SolveError> {
+    // Score of each letter against each opponent's shape, played as each shape.
+    let letters = game.len();
+    let mut scores = vec![vec![0; game.len()]; letters];
+    for &(opponent, letter) in guide {
+        if opponent >= game.len() || letter >= letters {
+            let message = format!("round ({}, {}) is not part of the game", opponent, letter);
+            return Err(SolveError::no_solution(message));
+        }
+        for (player, score) in scores[letter].iter_mut().enumerate() {
+            *score += game.score(player, opponent);
+        }
+    }
+    let mut best: Option<(i32, Vec<usize>)> = None;
+    let mut mapping = Vec::with_capacity(letters);
+    permutations(letters, &mut mapping, &mut |mapping| {
+        let score = zip(&scores, mapping)
+            .map(|(scores, &shape)| scores[shape])
+            .sum();
+        if best.as_ref().is_none_or(|(best, _)| score > *best) {
+            best = Some((score, mapping.to_vec()));
+        }
+    });
+    let strategy = Strategy::Shapes(best.map(|(_, mapping)| mapping).unwrap_or_default());
+    let tally = evaluate(game, guide, &strategy)?;
+    Ok((strategy, tally))
+}
+
+// Call `visit` on each permutation of 0..n extending `prefix`, in lexicographic order.
+fn permutations(n: usize, prefix: &mut Vec<usize>, visit: &mut impl FnMut(&[usize])) {
+    if prefix.len() == n {
+        visit(prefix);
+        return;
+    }
+    for next in 0..n {
+        if !prefix.contains(&next) {
+            prefix.push(next);
+            permutations(n, prefix, visit);
+            prefix.pop();
+        }
+    }
+}
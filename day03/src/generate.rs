@@ -0,0 +1,42 @@
+use crate::Day03;
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+// `size` groups of rucksacks, of up to 12 elves. The items other than the
+// badge are split between the rucksacks of a group, and between the
+// compartments of each rucksack but for the one item in both.
+impl Generator for Day03 {
+    const SIZE: usize = 100;
+
+    fn generate(rng: &mut StdRng, size: usize, params: &Self::Params) -> String {
+        let group = params.group.clamp(1, 12);
+        let mut input = String::new();
+        for _ in 0..size {
+            let mut items = ITEMS.to_vec();
+            items.shuffle(rng);
+            let badge = items[0];
+            for pool in items[1..].chunks(51 / group).take(group) {
+                let (left, right) = pool.split_at(pool.len() / 2);
+                let shared = if rng.gen_bool(0.25) { badge } else { left[0] };
+                let len = rng.gen_range(2..=16);
+                let mut comps = [vec![shared], vec![shared]];
+                if shared != badge {
+                    comps[0].push(badge);
+                }
+                for (comp, others) in comps.iter_mut().zip([&left[1..], right]) {
+                    while comp.len() < len {
+                        comp.push(*others.choose(rng).unwrap());
+                    }
+                    comp.shuffle(rng);
+                    input.extend(comp.iter().map(|item| *item as char));
+                }
+                input.push('\n');
+            }
+        }
+        input
+    }
+}
@@ -0,0 +1,219 @@
+use aoc_common::{params, parse_lines, Answer, SolveError, Solver};
+use std::fmt;
+use std::ops::BitAnd;
+
+mod generate;
+
+/// Priority of an item, from 1 for 'a' to 52 for 'Z'.
+pub fn priority(item: char) -> usize {
+    match item {
+        'a'..='z' => item as usize - 'a' as usize + 1,
+        'A'..='Z' => item as usize - 'A' as usize + 27,
+        _ => panic!("'{}' is not an item", item),
+    }
+}
+
+fn item(priority: usize) -> char {
+    match priority {
+        1..=26 => (b'a' + priority as u8 - 1) as char,
+        _ => (b'A' + priority as u8 - 27) as char,
+    }
+}
+
+/// A set of items, bit `p` being set when the item of priority `p` is in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Items(u64);
+
+impl Items {
+    const ALL: Items = Items(((1 << 52) - 1) << 1);
+
+    pub fn new(items: &str) -> Self {
+        Items(items.chars().fold(0, |set, item| set | 1 << priority(item)))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn contains(&self, item: char) -> bool {
+        self.0 & 1 << priority(item) != 0
+    }
+
+    /// The items, by increasing priority.
+    pub fn iter(&self) -> impl Iterator<Item = char> + '_ {
+        (1..=52).filter(|p| self.0 & 1 << p != 0).map(item)
+    }
+
+    pub fn priorities(&self) -> usize {
+        self.iter().map(priority).sum()
+    }
+}
+
+impl BitAnd for Items {
+    type Output = Items;
+
+    fn bitand(self, other: Items) -> Items {
+        Items(self.0 & other.0)
+    }
+}
+
+impl fmt::Display for Items {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.iter().collect::<String>())
+    }
+}
+
+/// The items in all the rucksacks of the `group`.
+pub fn common_items<'a>(group: impl IntoIterator<Item = &'a str>) -> Items {
+    let mut group = group.into_iter().peekable();
+    match group.peek() {
+        None => Items::default(),
+        Some(_) => group.map(Items::new).fold(Items::ALL, BitAnd::bitand),
+    }
+}
+
+// The one item of `items`, or an error telling which items `what` has.
+fn single(items: Items, what: impl fmt::Display) -> Result<char, SolveError> {
+    match items.len() {
+        1 => Ok(items.iter().next().unwrap()),
+        0 => Err(SolveError::no_solution(format!("{} has no item", what))),
+        _ => Err(SolveError::no_solution(format!(
+            "{} has several items: {}",
+            what, items
+        ))),
+    }
+}
+
+params! {
+    pub struct Params {
+        // Number of elves per group in part 2.
+        group: usize = 3,
+    }
+}
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    type Input = Vec<String>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_lines(input, |line| {
+            if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+                return Err(SolveError::at(
+                    line,
+                    &line[i..],
+                    format!("expected an item, found '{}'", c),
+                ));
+            }
+            if line.len() % 2 != 0 {
+                return Err(SolveError::at_end(line, "expected an even number of items"));
+            }
+            Ok(line.to_string())
+        })
+    }
+
+    fn part1(rucksacks: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let mut sum = 0;
+        for line in rucksacks {
+            let (comp1, comp2) = line.split_at(line.len() / 2);
+            let what = format_args!("the intersection of the compartments of {}", line);
+            sum += priority(single(common_items([comp1, comp2]), what)?);
+        }
+        Ok(sum.into())
+    }
+
+    fn part2(rucksacks: &Self::Input, params: &Self::Params) -> Result<Answer, SolveError> {
+        if params.group == 0 || rucksacks.len() % params.group != 0 {
+            return Err(SolveError::Param(format!(
+                "{} rucksacks cannot be split in groups of {}",
+                rucksacks.len(),
+                params.group
+            )));
+        }
+        let mut sum = 0;
+        for group in rucksacks.chunks(params.group) {
+            let badges = common_items(group.iter().map(String::as_str));
+            let what = format_args!("the badge of the group of {}", group[0]);
+            sum += priority(single(badges, what)?);
+        }
+        Ok(sum.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_generated};
+    use std::fs::read_to_string;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        solve::<Day03>(&read_to_string("./data/example.txt").unwrap(), part)
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(1), Ok(Answer::Int(157)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(2), Ok(Answer::Int(70)));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        // Against looking up the items of a rucksack in the others.
+        let priority = |item| {
+            ('a'..='z')
+                .chain('A'..='Z')
+                .position(|c| c == item)
+                .unwrap()
+                + 1
+        };
+        let shared = |first: &str, others: &[&str]| {
+            let mut items = first
+                .chars()
+                .filter(|&c| others.iter().all(|o| o.contains(c)));
+            priority(items.next().unwrap())
+        };
+        let solved = solve_generated::<Day03>(0..10, 10, &Params::default(), &[1, 2]);
+        for (input, answers) in solved {
+            let lines: Vec<&str> = input.lines().collect();
+            let part1: usize = lines
+                .iter()
+                .map(|line| {
+                    let (left, right) = line.split_at(line.len() / 2);
+                    shared(left, &[right])
+                })
+                .sum();
+            let part2: usize = lines
+                .chunks(3)
+                .map(|group| shared(group[0], &group[1..]))
+                .sum();
+            assert_eq!(answers, [part1.into(), part2.into()]);
+        }
+    }
+
+    #[test]
+    fn common_items_of_groups() {
+        let input = read_to_string("./data/example.txt").unwrap();
+        let rucksacks: Vec<&str> = input.lines().collect();
+        assert_eq!(
+            common_items(rucksacks[..3].iter().copied()).to_string(),
+            "r"
+        );
+        assert_eq!(
+            common_items(rucksacks[..2].iter().copied()).to_string(),
+            "frsFM"
+        );
+        assert_eq!(common_items(rucksacks.iter().copied()), Items::default());
+        let params = Params { group: 2 };
+        let error = Day03::part2(&Day03::parse(&input).unwrap(), &params);
+        assert!(matches!(error, Err(SolveError::NoSolution(_))));
+    }
+}
@@ -0,0 +1,22 @@
+use crate::Day04;
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// `size` pairs of section ranges.
+impl Generator for Day04 {
+    const SIZE: usize = 1000;
+
+    fn generate(rng: &mut StdRng, size: usize, _params: &Self::Params) -> String {
+        let mut range = || {
+            let start = rng.gen_range(1..=99);
+            (start, rng.gen_range(start..=99))
+        };
+        (0..size)
+            .map(|_| {
+                let ((a, b), (c, d)) = (range(), range());
+                format!("{}-{},{}-{}\n", a, b, c, d)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,84 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// The sections from `start` to `end`, both included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    pub start: u32,
+    pub end: u32,
+}
+
+impl Interval {
+    /// None if the interval would end before its start.
+    pub fn new(start: u32, end: u32) -> Option<Self> {
+        (start <= end).then_some(Self { start, end })
+    }
+
+    /// Number of sections, at least one.
+    pub fn sections(&self) -> u64 {
+        (self.end - self.start) as u64 + 1
+    }
+
+    pub fn contains(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// None if a section between both intervals is in neither.
+    pub fn union(&self, other: &Interval) -> Option<Interval> {
+        let touch =
+            self.start <= other.end.saturating_add(1) && other.start <= self.end.saturating_add(1);
+        touch.then(|| Interval {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        })
+    }
+}
+
+/// The indices of the intervals that overlap, each pair once and in order,
+/// found with a sweep line over the starts of the intervals.
+pub fn overlapping_pairs(intervals: &[Interval]) -> Vec<(usize, usize)> {
+    let mut order: Vec<usize> = (0..intervals.len()).collect();
+    order.sort_by_key(|&i| intervals[i].start);
+    // The intervals met so far that may still overlap the next ones, by end.
+    let mut active: BinaryHeap<Reverse<(u32, usize)>> = BinaryHeap::new();
+    let mut pairs = Vec::new();
+    for i in order {
+        while let Some(Reverse((end, _))) = active.peek() {
+            if *end >= intervals[i].start {
+                break;
+            }
+            active.pop();
+        }
+        pairs.extend(active.iter().map(|Reverse((_, j))| (i.min(*j), i.max(*j))));
+        active.push(Reverse((intervals[i].end, i)));
+    }
+    pairs.sort_unstable();
+    pairs
+}
+
+/// Number of sections in at least one of the intervals.
+pub fn covered(intervals: &[Interval]) -> u64 {
+    let mut sorted = intervals.to_vec();
+    sorted.sort_unstable();
+    let mut total = 0;
+    let mut current: Option<Interval> = None;
+    for interval in sorted {
+        current = match current {
+            Some(merged) if merged.overlaps(&interval) => merged.union(&interval),
+            Some(merged) => {
+                total += merged.sections();
+                Some(interval)
+            }
+            None => Some(interval),
+        };
+    }
+    total + current.map_or(0, |merged| merged.sections())
+}
@@ -0,0 +1,113 @@
+use aoc_common::{parse_lines, parse_token, scan, Answer, SolveError, Solver};
+
+mod generate;
+mod interval;
+
+pub use interval::{covered, overlapping_pairs, Interval};
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    type Input = Vec<(Interval, Interval)>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_lines(input, |line| {
+            let [a, b, c, d] = scan(line, "{}-{},{}-{}")?;
+            let section = |field| parse_token::<u32>(line, field);
+            let interval = |start, end| {
+                Interval::new(section(start)?, section(end)?).ok_or_else(|| {
+                    SolveError::at(line, start, "the sections cannot end before they start")
+                })
+            };
+            Ok((interval(a, b)?, interval(c, d)?))
+        })
+    }
+
+    fn part1(pairs: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let full_overlaps = pairs
+            .iter()
+            .filter(|(a, b)| a.contains(b) || b.contains(a))
+            .count();
+        Ok(full_overlaps.into())
+    }
+
+    fn part2(pairs: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let partial_overlaps = pairs.iter().filter(|(a, b)| a.overlaps(b)).count();
+        Ok(partial_overlaps.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_generated};
+    use std::collections::HashSet;
+    use std::fs::read_to_string;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        solve::<Day04>(&read_to_string("./data/example.txt").unwrap(), part)
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(1), Ok(Answer::Int(2)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(2), Ok(Answer::Int(4)));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        // Against the sets of sections of each pair.
+        let solved = solve_generated::<Day04>(0..10, 50, &(), &[1, 2]);
+        for (input, answers) in solved {
+            let (mut contained, mut overlapping) = (0, 0);
+            for line in input.lines() {
+                let [a, b]: [HashSet<u32>; 2] = line
+                    .split(',')
+                    .map(|range| {
+                        let (start, end) = range.split_once('-').unwrap();
+                        (start.parse().unwrap()..=end.parse().unwrap()).collect()
+                    })
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .unwrap();
+                contained += (a.is_subset(&b) || b.is_subset(&a)) as usize;
+                overlapping += !a.is_disjoint(&b) as usize;
+            }
+            assert_eq!(answers, [contained.into(), overlapping.into()]);
+        }
+    }
+
+    #[test]
+    fn intervals() {
+        let (a, b, c) = (
+            Interval::new(2, 8).unwrap(),
+            Interval::new(3, 7).unwrap(),
+            Interval::new(9, 12).unwrap(),
+        );
+        assert!(a.contains(&b) && !b.contains(&a));
+        assert_eq!(a.intersection(&c), None);
+        assert_eq!(a.union(&c), Interval::new(2, 12));
+        assert_eq!(b.union(&c), None);
+        let crew: Vec<Interval> = Day04::parse(&read_to_string("./data/example.txt").unwrap())
+            .unwrap()
+            .into_iter()
+            .flat_map(|(a, b)| [a, b])
+            .collect();
+        // Checked against every pair.
+        let mut expected = Vec::new();
+        for i in 0..crew.len() {
+            for j in i + 1..crew.len() {
+                if crew[i].overlaps(&crew[j]) {
+                    expected.push((i, j));
+                }
+            }
+        }
+        assert_eq!(overlapping_pairs(&crew), expected);
+        assert_eq!(covered(&crew), 8);
+    }
+}
@@ -0,0 +1,92 @@
+use aoc_common::SolveError;
+
+/// Parse a drawing of stacks of crates, given by its lines from top to bottom,
+/// the last one numbering the stacks. A crate is a `[label]`, its stack being
+/// the number it is above. `input` is the whole text, to locate errors.
+pub fn parse_drawing(input: &str, drawing: &[&str]) -> Result<Vec<Vec<String>>, SolveError> {
+    let (footer, rows) = match drawing.split_last() {
+        Some(split) => split,
+        None => {
+            return Err(SolveError::at_end(
+                input,
+                "expected a drawing of the stacks",
+            ))
+        }
+    };
+    // Column of the middle of each stack number.
+    let mut centres = Vec::new();
+    for token in footer.split_ascii_whitespace() {
+        let expected = centres.len() + 1;
+        if token != expected.to_string() {
+            let message = format!("expected stack number {}", expected);
+            return Err(SolveError::at(input, token, message));
+        }
+        centres.push(offset(footer, token) + (token.len() - 1) / 2);
+    }
+    if centres.is_empty() {
+        return Err(SolveError::at(
+            input,
+            &footer[footer.len()..],
+            "expected stack numbers",
+        ));
+    }
+    let period = match centres[..] {
+        [first, second, ..] => second - first,
+        _ => 1,
+    };
+    let mut stacks: Vec<Vec<String>> = vec![Vec::new(); centres.len()];
+    for line in rows.iter().rev() {
+        let mut rest = line.trim_start();
+        while !rest.is_empty() {
+            let label = match rest.strip_prefix('[').and_then(|r| r.split_once(']')) {
+                Some((label, _)) if !label.is_empty() => label,
+                _ => return Err(SolveError::at(input, rest, "expected a [crate]")),
+            };
+            let centre = offset(line, rest) + label.len().div_ceil(2);
+            let stack = (centre + period / 2)
+                .checked_sub(centres[0])
+                .map(|d| d / period);
+            match stack {
+                Some(stack) if stack < stacks.len() => stacks[stack].push(label.to_string()),
+                _ => return Err(SolveError::at(input, rest, "crate out of stacks")),
+            }
+            rest = rest[label.len() + 2..].trim_start();
+        }
+    }
+    Ok(stacks)
+}
+
+// Byte offset of `token` in `line`, of which it is a slice.
+fn offset(line: &str, token: &str) -> usize {
+    token.as_ptr() as usize - line.as_ptr() as usize
+}
+
+/// Draw the stacks as in the puzzle, with a column per stack wide enough for
+/// the longest label and the stack numbers.
+pub fn render(stacks: &[Vec<String>]) -> String {
+    let label = stacks.iter().flatten().map(|l| l.len()).max().unwrap_or(1);
+    let width = (label + 2).max(stacks.len().to_string().len() + 2);
+    let height = stacks.iter().map(|stack| stack.len()).max().unwrap_or(0);
+    let mut drawing = String::new();
+    let mut line = |cells: Vec<String>| {
+        drawing += &cells.join(" ");
+        drawing.push('\n');
+    };
+    for level in (0..height).rev() {
+        line(
+            stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(label) => format!("{:<width$}", format!("[{}]", label)),
+                    None => " ".repeat(width),
+                })
+                .collect(),
+        );
+    }
+    line(
+        (1..=stacks.len())
+            .map(|number| format!(" {:<1$}", number, width - 1))
+            .collect(),
+    );
+    drawing
+}
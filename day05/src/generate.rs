@@ -0,0 +1,43 @@
+use crate::{render, Day05};
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// `size` moves between up to 9 stacks, which are never emptied.
+impl Generator for Day05 {
+    const SIZE: usize = 500;
+
+    fn generate(rng: &mut StdRng, size: usize, _params: &Self::Params) -> String {
+        let numstacks = rng.gen_range(2..=9);
+        // More crates than stacks, so that one can always be moved.
+        let mut stacks: Vec<Vec<char>> = (0..numstacks)
+            .map(|i| {
+                (0..rng.gen_range(1 + (i == 0) as usize..=8))
+                    .map(|_| rng.gen_range('A'..='Z'))
+                    .collect()
+            })
+            .collect();
+        let labels: Vec<Vec<String>> = stacks
+            .iter()
+            .map(|stack| stack.iter().map(char::to_string).collect())
+            .collect();
+        let mut input = render(&labels);
+        input.push('\n');
+        // Simulate the moves to only take crates that are there.
+        for _ in 0..size {
+            let from = loop {
+                let from = rng.gen_range(0..numstacks);
+                if stacks[from].len() > 1 {
+                    break from;
+                }
+            };
+            let to = (from + rng.gen_range(1..numstacks)) % numstacks;
+            let num = rng.gen_range(1..stacks[from].len());
+            let start = stacks[from].len() - num;
+            let moved: Vec<char> = stacks[from].drain(start..).collect();
+            stacks[to].extend(moved);
+            input += &format!("move {} from {} to {}\n", num, from + 1, to + 1);
+        }
+        input
+    }
+}
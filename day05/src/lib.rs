@@ -0,0 +1,191 @@
+use aoc_common::trace::{self, Frame};
+use aoc_common::{parse_token, scan, Answer, Grid, SolveError, Solver};
+
+mod drawing;
+mod generate;
+mod yard;
+
+pub use drawing::{parse_drawing, render};
+pub use yard::{Batched, CargoYard, Crane, CrateMover9000, CrateMover9001, Move};
+
+pub struct Input {
+    stacks: Vec<Vec<String>>,
+    commands: Vec<Move>,
+}
+
+// The drawing of the stacks, under the move that led to them.
+fn frame(title: String, yard: &CargoYard) -> Frame {
+    let cells = Grid::parse_padded(&yard.render(), "crates", ' ', |_, c| Some(c)).unwrap();
+    Frame::new(title, cells)
+}
+
+fn simulate(input: &Input, crane: &dyn Crane) -> Result<Answer, SolveError> {
+    let mut yard = CargoYard::new(input.stacks.clone());
+    trace::trace(|| frame("start".to_string(), &yard));
+    for &step in &input.commands {
+        yard.apply(crane, step)?;
+        trace::trace(|| frame(step.to_string(), &yard));
+    }
+    yard.tops().map(Answer::from)
+}
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    type Input = Input;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let lines = input.lines().collect::<Vec<_>>();
+        let groups = lines.split(|l| l.is_empty()).collect::<Vec<_>>();
+        if groups.len() < 2 {
+            return Err(SolveError::at_end(
+                input,
+                "expected an empty line between the stacks and the procedure",
+            ));
+        }
+        let cargo: Vec<&str> = groups[0].into();
+        let procedure = groups[1];
+
+        let stacks = parse_drawing(input, &cargo)?;
+        let numstacks = stacks.len();
+
+        // Build commands
+        let command = |l: &str, line: usize| -> Result<Move, SolveError> {
+            let [num, from, to] = scan(l, "move {} from {} to {}")?;
+            let stack = |token| match parse_token::<usize>(l, token)? {
+                n if 1 <= n && n <= numstacks => Ok(n),
+                _ => Err(SolveError::at(l, token, "no such stack")),
+            };
+            Ok(Move {
+                num: parse_token(l, num)?,
+                from: stack(from)?,
+                to: stack(to)?,
+                line,
+            })
+        };
+        let commands = procedure
+            .iter()
+            .enumerate()
+            .map(|(i, l)| {
+                let line = cargo.len() + 1 + i;
+                command(l, line + 1).map_err(|err| err.after_lines(line))
+            })
+            .collect::<Result<Vec<Move>, SolveError>>()?;
+
+        Ok(Input { stacks, commands })
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        simulate(input, &CrateMover9000)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        simulate(input, &CrateMover9001)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_generated};
+    use std::fs::read_to_string;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        solve::<Day05>(&read_to_string("./data/example.txt").unwrap(), part)
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(1), Ok("CMZ".into()));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(2), Ok("MCD".into()));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        // Against the drawing read at fixed columns and the crates popped one
+        // at a time for the CrateMover 9000.
+        for (input, answers) in solve_generated::<Day05>(0..10, 30, &(), &[1, 2]) {
+            let (drawing, procedure) = input.split_once("\n\n").unwrap();
+            let mut rows: Vec<&str> = drawing.lines().collect();
+            let numbers = rows.pop().unwrap();
+            let start: Vec<Vec<char>> = (0..numbers.split_whitespace().count())
+                .map(|i| {
+                    let label =
+                        |row: &&str| row.chars().nth(1 + 4 * i).filter(char::is_ascii_uppercase);
+                    rows.iter().rev().filter_map(label).collect()
+                })
+                .collect();
+            let tops = |one_at_a_time: bool| {
+                let mut stacks = start.clone();
+                for line in procedure.lines() {
+                    let words: Vec<usize> =
+                        line.split(' ').filter_map(|w| w.parse().ok()).collect();
+                    let (num, from, to) = (words[0], words[1] - 1, words[2] - 1);
+                    if one_at_a_time {
+                        for _ in 0..num {
+                            let label = stacks[from].pop().unwrap();
+                            stacks[to].push(label);
+                        }
+                    } else {
+                        let at = stacks[from].len() - num;
+                        let moved = stacks[from].split_off(at);
+                        stacks[to].extend(moved);
+                    }
+                }
+                let tops: String = stacks.iter().map(|stack| stack.last().unwrap()).collect();
+                Answer::from(tops)
+            };
+            assert_eq!(answers, [tops(true), tops(false)]);
+        }
+    }
+
+    #[test]
+    fn cranes() {
+        let input = Day05::parse(&read_to_string("./data/example.txt").unwrap()).unwrap();
+        let mut yard = CargoYard::new(input.stacks.clone());
+        // A batch of one crate is a CrateMover 9000, a batch of all of them a 9001.
+        yard.run(&Batched(1), &input.commands).unwrap();
+        assert_eq!(yard.tops(), Ok("CMZ".to_string()));
+        while yard.undo().is_some() {}
+        assert_eq!(yard.stacks(), input.stacks);
+        yard.run(&Batched(3), &input.commands).unwrap();
+        assert_eq!(yard.tops(), Ok("MCD".to_string()));
+        yard.undo();
+        let step = Move {
+            num: 4,
+            ..input.commands[3]
+        };
+        let error = yard.apply(&CrateMover9001, step).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "no solution: line 9: 'move 4 from 1 to 2' takes 4 crates from a stack of 2"
+        );
+    }
+
+    #[test]
+    fn drawings() {
+        for path in ["./data/example.txt", "./data/input.txt"] {
+            let input = read_to_string(path).unwrap();
+            let (drawing, _) = input.split_once("\n\n").unwrap();
+            let stacks = Day05::parse(&input).unwrap().stacks;
+            assert_eq!(render(&stacks), format!("{}\n", drawing));
+        }
+        // Wider yards and longer labels get wider columns.
+        let stacks: Vec<Vec<String>> = (0..12)
+            .map(|i| (0..i % 4).map(|j| format!("{}{}", i, j)).collect())
+            .collect();
+        let drawing = render(&stacks);
+        let lines: Vec<&str> = drawing.lines().collect();
+        assert_eq!(
+            lines[0].trim(),
+            "[32]                    [72]                    [112]"
+        );
+        assert!(lines[3].ends_with(" 10    11    12  "));
+        assert_eq!(parse_drawing(&drawing, &lines), Ok(stacks));
+    }
+}
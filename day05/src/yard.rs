@@ -0,0 +1,152 @@
+use crate::drawing::render;
+use aoc_common::SolveError;
+use std::fmt;
+
+/// A step of the procedure: move `num` crates from stack `from` to stack `to`,
+/// stacks being numbered from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub num: usize,
+    pub from: usize,
+    pub to: usize,
+    /// Line of the move in the input.
+    pub line: usize,
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.num, self.from, self.to)
+    }
+}
+
+/// How a crane sets down the crates it took off the top of a stack.
+pub trait Crane {
+    /// Put the `taken` crates, listed from bottom to top as they were in their
+    /// stack, on top of the stack `to`.
+    fn put(&self, taken: &[String], to: &mut Vec<String>);
+}
+
+/// Moves crates one at a time, reversing their order.
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn put(&self, taken: &[String], to: &mut Vec<String>) {
+        to.extend(taken.iter().rev().cloned());
+    }
+}
+
+/// Moves all the crates at once, keeping their order.
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn put(&self, taken: &[String], to: &mut Vec<String>) {
+        to.extend_from_slice(taken);
+    }
+}
+
+/// Moves up to `batch` crates at once, from the top: the order of the crates is
+/// kept within a batch and reversed between batches.
+pub struct Batched(pub usize);
+
+impl Crane for Batched {
+    fn put(&self, taken: &[String], to: &mut Vec<String>) {
+        for batch in taken.rchunks(self.0.max(1)) {
+            to.extend_from_slice(batch);
+        }
+    }
+}
+
+// What it takes to undo a move.
+#[derive(Debug, Clone)]
+struct Undo {
+    step: Move,
+    taken: Vec<String>,
+}
+
+/// Stacks of crates, moved around by a crane and keeping the history of the
+/// moves to undo them.
+#[derive(Debug, Clone)]
+pub struct CargoYard {
+    stacks: Vec<Vec<String>>,
+    history: Vec<Undo>,
+}
+
+impl CargoYard {
+    pub fn new(stacks: Vec<Vec<String>>) -> Self {
+        Self {
+            stacks,
+            history: Vec::new(),
+        }
+    }
+
+    /// The crates of each stack, from bottom to top.
+    pub fn stacks(&self) -> &[Vec<String>] {
+        &self.stacks
+    }
+
+    /// Apply `step` with the `crane`, checking that both stacks exist and that
+    /// there are enough crates to take.
+    pub fn apply(&mut self, crane: &dyn Crane, step: Move) -> Result<(), SolveError> {
+        let numstacks = self.stacks.len();
+        let invalid = |message: String| {
+            SolveError::no_solution(format!("line {}: '{}' {}", step.line, step, message))
+        };
+        for stack in [step.from, step.to] {
+            if stack == 0 || stack > numstacks {
+                return Err(invalid(format!(
+                    "refers to stack {} out of {}",
+                    stack, numstacks
+                )));
+            }
+        }
+        let from = &mut self.stacks[step.from - 1];
+        if step.num > from.len() {
+            return Err(invalid(format!(
+                "takes {} crates from a stack of {}",
+                step.num,
+                from.len()
+            )));
+        }
+        let taken = from.split_off(from.len() - step.num);
+        crane.put(&taken, &mut self.stacks[step.to - 1]);
+        self.history.push(Undo { step, taken });
+        Ok(())
+    }
+
+    /// Apply all the `steps` with the `crane`, stopping at the first invalid one.
+    pub fn run(&mut self, crane: &dyn Crane, steps: &[Move]) -> Result<(), SolveError> {
+        steps.iter().try_for_each(|&step| self.apply(crane, step))
+    }
+
+    /// Undo the last move, returning it.
+    pub fn undo(&mut self) -> Option<Move> {
+        let Undo { step, taken } = self.history.pop()?;
+        let to = &mut self.stacks[step.to - 1];
+        to.truncate(to.len() - step.num);
+        self.stacks[step.from - 1].extend(taken);
+        Some(step)
+    }
+
+    /// The moves applied so far, in order.
+    pub fn history(&self) -> impl Iterator<Item = &Move> {
+        self.history.iter().map(|undo| &undo.step)
+    }
+
+    /// The labels of the crates on top of the stacks.
+    pub fn tops(&self) -> Result<String, SolveError> {
+        self.stacks
+            .iter()
+            .enumerate()
+            .map(|(i, s)| {
+                s.last()
+                    .map(String::as_str)
+                    .ok_or_else(|| SolveError::no_solution(format!("stack {} is empty", i + 1)))
+            })
+            .collect()
+    }
+
+    /// The stacks drawn as in the puzzle.
+    pub fn render(&self) -> String {
+        render(&self.stacks)
+    }
+}
@@ -0,0 +1,22 @@
+use crate::Day06;
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+// A datastream of `size` characters, with at least one start-of-message marker.
+impl Generator for Day06 {
+    const SIZE: usize = 4096;
+
+    fn generate(rng: &mut StdRng, size: usize, _params: &Self::Params) -> String {
+        let mut letters: Vec<char> = ('a'..='z').collect();
+        letters.shuffle(rng);
+        let marker = &letters[..14];
+        let mut stream: Vec<char> = (0..size.saturating_sub(14))
+            .map(|_| rng.gen_range('a'..='z'))
+            .collect();
+        let at = rng.gen_range(0..=stream.len());
+        stream.splice(at..at, marker.iter().copied());
+        stream.into_iter().collect()
+    }
+}
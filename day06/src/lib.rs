@@ -0,0 +1,231 @@
+use aoc_common::{Answer, SolveError, Solver};
+use std::io::{self, BufReader, Bytes, Read};
+
+mod generate;
+
+// The last `size` bytes, by the number of times each value is in.
+struct Window {
+    size: usize,
+    counts: [u32; 256],
+    // Number of values in more than once.
+    repeated: usize,
+}
+
+impl Window {
+    fn add(&mut self, byte: u8) {
+        self.counts[byte as usize] += 1;
+        if self.counts[byte as usize] == 2 {
+            self.repeated += 1;
+        }
+    }
+
+    fn remove(&mut self, byte: u8) {
+        if self.counts[byte as usize] == 2 {
+            self.repeated -= 1;
+        }
+        self.counts[byte as usize] -= 1;
+    }
+}
+
+/// A marker: `size` different bytes in a row, ending after `position` bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Marker {
+    pub size: usize,
+    pub position: u64,
+}
+
+/// Looks for markers of several sizes at once, fed one byte at a time in
+/// constant time and memory.
+pub struct Detector {
+    windows: Vec<Window>,
+    // The last bytes, byte `i` of the stream being at `i % history.len()`.
+    history: Vec<u8>,
+    position: u64,
+}
+
+impl Detector {
+    pub fn new(sizes: &[usize]) -> Self {
+        assert!(
+            sizes.iter().all(|&size| size > 0),
+            "markers cannot be empty"
+        );
+        let windows = sizes
+            .iter()
+            .map(|&size| Window {
+                size,
+                counts: [0; 256],
+                repeated: 0,
+            })
+            .collect();
+        let longest = sizes.iter().copied().max().unwrap_or(1);
+        Self {
+            windows,
+            history: vec![0; longest],
+            position: 0,
+        }
+    }
+
+    pub fn push(&mut self, byte: u8) {
+        let len = self.history.len() as u64;
+        for window in &mut self.windows {
+            if let Some(old) = self.position.checked_sub(window.size as u64) {
+                window.remove(self.history[(old % len) as usize]);
+            }
+            window.add(byte);
+        }
+        self.history[(self.position % len) as usize] = byte;
+        self.position += 1;
+    }
+
+    /// The markers ending with the last byte.
+    pub fn found(&self) -> impl Iterator<Item = Marker> + '_ {
+        (0..self.windows.len()).filter_map(|i| self.found_at(i))
+    }
+
+    fn found_at(&self, window: usize) -> Option<Marker> {
+        let Window { size, repeated, .. } = self.windows[window];
+        (repeated == 0 && self.position >= size as u64).then_some(Marker {
+            size,
+            position: self.position,
+        })
+    }
+}
+
+/// All the markers of the `sizes` in a stream, in order of position and then
+/// of size, read as it goes.
+pub struct Markers<R> {
+    bytes: Bytes<BufReader<R>>,
+    detector: Detector,
+    // Next window to check for a marker ending at the current position.
+    next: usize,
+}
+
+pub fn markers<R: Read>(reader: R, sizes: &[usize]) -> Markers<R> {
+    Markers {
+        bytes: BufReader::new(reader).bytes(),
+        detector: Detector::new(sizes),
+        next: sizes.len(),
+    }
+}
+
+impl<R: Read> Iterator for Markers<R> {
+    type Item = io::Result<Marker>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            while self.next < self.detector.windows.len() {
+                self.next += 1;
+                if let Some(marker) = self.detector.found_at(self.next - 1) {
+                    return Some(Ok(marker));
+                }
+            }
+            match self.bytes.next()? {
+                Ok(byte) => self.detector.push(byte),
+                Err(err) => return Some(Err(err)),
+            }
+            self.next = 0;
+        }
+    }
+}
+
+/// The position of the first marker of each of the `sizes`, reading the stream
+/// until all of them are found.
+pub fn first_markers(reader: impl Read, sizes: &[usize]) -> io::Result<Vec<Option<u64>>> {
+    let mut first = vec![None; sizes.len()];
+    let mut missing = sizes.len();
+    for marker in markers(reader, sizes) {
+        let marker = marker?;
+        for (size, first) in sizes.iter().zip(&mut first) {
+            if *size == marker.size && first.is_none() {
+                *first = Some(marker.position);
+                missing -= 1;
+            }
+        }
+        if missing == 0 {
+            break;
+        }
+    }
+    Ok(first)
+}
+
+fn marker(input: &str, n: usize) -> Result<Answer, SolveError> {
+    match markers(input.as_bytes(), &[n]).next() {
+        Some(Ok(marker)) => Ok(marker.position.into()),
+        _ => Err(SolveError::no_solution(format!(
+            "no {} different characters in a row",
+            n
+        ))),
+    }
+}
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    type Input = String;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        marker(input, 4)
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        marker(input, 14)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_generated};
+    use std::collections::HashSet;
+    use std::fs::read_to_string;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        solve::<Day06>(&read_to_string("./data/example.txt").unwrap(), part)
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(1), Ok(Answer::Int(7)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(2), Ok(Answer::Int(19)));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        // Against checking every window.
+        let solved = solve_generated::<Day06>(0..10, 200, &(), &[1, 2]);
+        for (input, answers) in solved {
+            let first = |size| {
+                let window = input
+                    .as_bytes()
+                    .windows(size)
+                    .position(|window| window.iter().collect::<HashSet<_>>().len() == size);
+                Answer::from(window.unwrap() + size)
+            };
+            assert_eq!(answers, [first(4), first(14)]);
+        }
+    }
+
+    #[test]
+    fn streams() {
+        let stream = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        let found: Vec<u64> = markers(stream.as_bytes(), &[4])
+            .map(|marker| marker.unwrap().position)
+            .take(4)
+            .collect();
+        assert_eq!(found, [7, 8, 9, 10]);
+        // A stream read in pieces, both sizes in one pass.
+        let (start, end) = stream.as_bytes().split_at(10);
+        let reader = start.chain(end);
+        let first = first_markers(reader, &[14, 4, 27]).unwrap();
+        assert_eq!(first, [Some(19), Some(7), None]);
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::ops::RangeBounds;
+
+/// Index of a file or directory in its `FileSystem`.
+pub type NodeId = usize;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Kind {
+    File(u64),
+    Dir(BTreeMap<String, NodeId>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Node {
+    name: String,
+    parent: Option<NodeId>,
+    kind: Kind,
+}
+
+/// A tree of directories and files, stored in an arena: nodes refer to their
+/// parent and children by index, and a node always comes after its parent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileSystem {
+    nodes: Vec<Node>,
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FileSystem {
+    pub const ROOT: NodeId = 0;
+
+    /// A file system with only the root directory.
+    pub fn new() -> Self {
+        let root = Node {
+            name: "/".to_string(),
+            parent: None,
+            kind: Kind::Dir(BTreeMap::new()),
+        };
+        Self { nodes: vec![root] }
+    }
+
+    /// Number of files and directories, the root included.
+    pub fn num_nodes(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn name(&self, node: NodeId) -> &str {
+        &self.nodes[node].name
+    }
+
+    /// None for the root.
+    pub fn parent(&self, node: NodeId) -> Option<NodeId> {
+        self.nodes[node].parent
+    }
+
+    pub fn is_dir(&self, node: NodeId) -> bool {
+        matches!(self.nodes[node].kind, Kind::Dir(_))
+    }
+
+    /// The entries of a directory, by name, none for a file.
+    pub fn children(&self, node: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        let children = match &self.nodes[node].kind {
+            Kind::Dir(children) => Some(children.values().copied()),
+            Kind::File(_) => None,
+        };
+        children.into_iter().flatten()
+    }
+
+    pub fn child(&self, dir: NodeId, name: &str) -> Option<NodeId> {
+        match &self.nodes[dir].kind {
+            Kind::Dir(children) => children.get(name).copied(),
+            Kind::File(_) => None,
+        }
+    }
+
+    fn add(&mut self, dir: NodeId, name: &str, kind: Kind) -> Option<NodeId> {
+        let id = self.nodes.len();
+        match &mut self.nodes[dir].kind {
+            Kind::Dir(children) => children.insert(name.to_string(), id),
+            Kind::File(_) => return None,
+        };
+        self.nodes.push(Node {
+            name: name.to_string(),
+            parent: Some(dir),
+            kind,
+        });
+        Some(id)
+    }
+
+    /// The directory `name` in `dir`, created if needed. None if `dir` is a
+    /// file or `name` is already a file.
+    pub fn add_dir(&mut self, dir: NodeId, name: &str) -> Option<NodeId> {
+        match self.child(dir, name) {
+            Some(node) => self.is_dir(node).then_some(node),
+            None => self.add(dir, name, Kind::Dir(BTreeMap::new())),
+        }
+    }
+
+    /// The file `name` of `size` in `dir`, created if needed. None if `dir` is
+    /// a file or `name` is already a directory or a file of another size.
+    pub fn add_file(&mut self, dir: NodeId, name: &str, size: u64) -> Option<NodeId> {
+        match self.child(dir, name) {
+            Some(node) => (self.nodes[node].kind == Kind::File(size)).then_some(node),
+            None => self.add(dir, name, Kind::File(size)),
+        }
+    }
+
+    /// The absolute path of a node, such as `/a/e`.
+    pub fn path(&self, node: NodeId) -> String {
+        let mut names = Vec::new();
+        let mut current = node;
+        while let Some(parent) = self.parent(current) {
+            names.push(self.name(current));
+            current = parent;
+        }
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+
+    /// The node at an absolute path.
+    pub fn lookup(&self, path: &str) -> Option<NodeId> {
+        let path = path.strip_prefix('/')?;
+        path.split('/')
+            .filter(|name| !name.is_empty())
+            .try_fold(Self::ROOT, |dir, name| self.child(dir, name))
+    }
+
+    /// The size of each node, that of a directory being the total size of the
+    /// files it contains.
+    pub fn sizes(&self) -> Vec<u64> {
+        let mut sizes: Vec<u64> = self
+            .nodes
+            .iter()
+            .map(|node| match node.kind {
+                Kind::File(size) => size,
+                Kind::Dir(_) => 0,
+            })
+            .collect();
+        // Children come after their parent: add them up from the end.
+        for node in (1..self.nodes.len()).rev() {
+            let parent = self.nodes[node].parent.unwrap();
+            sizes[parent] += sizes[node];
+        }
+        sizes
+    }
+
+    pub fn size(&self, node: NodeId) -> u64 {
+        match &self.nodes[node].kind {
+            Kind::File(size) => *size,
+            Kind::Dir(children) => children.values().map(|&child| self.size(child)).sum(),
+        }
+    }
+
+    // The nodes under `node`, itself included, parents before their children.
+    fn walk(&self, node: NodeId, visit: &mut impl FnMut(NodeId, usize), depth: usize) {
+        visit(node, depth);
+        for child in self.children(node) {
+            self.walk(child, visit, depth + 1);
+        }
+    }
+
+    /// The files and directories called `name`, if given, and whose size is in
+    /// `sizes`, from the root down.
+    pub fn find(&self, name: Option<&str>, sizes: impl RangeBounds<u64>) -> Vec<NodeId> {
+        let all = self.sizes();
+        let mut found = Vec::new();
+        self.walk(
+            Self::ROOT,
+            &mut |node, _| {
+                if name.is_none_or(|name| self.name(node) == name) && sizes.contains(&all[node]) {
+                    found.push(node);
+                }
+            },
+            0,
+        );
+        found
+    }
+
+    /// The size and path of every directory, one per line and each after its
+    /// subdirectories, as `du` prints them.
+    pub fn du(&self) -> String {
+        let sizes = self.sizes();
+        let mut report = String::new();
+        self.du_dir(Self::ROOT, &sizes, &mut report);
+        report
+    }
+
+    fn du_dir(&self, dir: NodeId, sizes: &[u64], report: &mut String) {
+        for child in self.children(dir).filter(|&child| self.is_dir(child)) {
+            self.du_dir(child, sizes, report);
+        }
+        writeln!(report, "{}\t{}", sizes[dir], self.path(dir)).unwrap();
+    }
+
+    /// The tree drawn as in the puzzle statement.
+    pub fn tree(&self) -> String {
+        let mut tree = String::new();
+        self.walk(
+            Self::ROOT,
+            &mut |node, depth| {
+                let indent = "  ".repeat(depth);
+                let name = self.name(node);
+                match self.nodes[node].kind {
+                    Kind::File(size) => {
+                        writeln!(tree, "{}- {} (file, size={})", indent, name, size)
+                    }
+                    Kind::Dir(_) => writeln!(tree, "{}- {} (dir)", indent, name),
+                }
+                .unwrap()
+            },
+            0,
+        );
+        tree
+    }
+}
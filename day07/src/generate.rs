@@ -0,0 +1,46 @@
+use crate::Day07;
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// Transcript of a depth-first exploration of `size` directories.
+impl Generator for Day07 {
+    const SIZE: usize = 200;
+
+    fn generate(rng: &mut StdRng, size: usize, _params: &Self::Params) -> String {
+        let mut input = "$ cd /\n".to_string();
+        let mut left = size.saturating_sub(1);
+        explore(rng, &mut input, &mut left);
+        input
+    }
+}
+
+fn name(rng: &mut StdRng) -> String {
+    (0..rng.gen_range(1..=8))
+        .map(|_| rng.gen_range('a'..='z'))
+        .collect()
+}
+
+// List the current directory and explore its subdirectories, `left` being the
+// number of directories that remain to be created.
+fn explore(rng: &mut StdRng, input: &mut String, left: &mut usize) {
+    input.push_str("$ ls\n");
+    let numdirs = rng.gen_range(0..=4).min(*left);
+    *left -= numdirs;
+    // The index keeps names unique within the directory.
+    let dirs: Vec<String> = (0..numdirs)
+        .map(|i| format!("{}{}", name(rng), i))
+        .collect();
+    for dir in &dirs {
+        input.push_str(&format!("dir {}\n", dir));
+    }
+    for i in 0..rng.gen_range(0..=5) {
+        let size = rng.gen_range(1000..=300000);
+        input.push_str(&format!("{} {}{}.{}\n", size, name(rng), i, name(rng)));
+    }
+    for dir in dirs {
+        input.push_str(&format!("$ cd {}\n", dir));
+        explore(rng, input, left);
+        input.push_str("$ cd ..\n");
+    }
+}
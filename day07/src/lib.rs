@@ -0,0 +1,145 @@
+use aoc_common::{Answer, SolveError, Solver};
+
+mod fs;
+mod generate;
+mod transcript;
+
+pub use fs::{FileSystem, NodeId};
+pub use transcript::replay;
+
+// The sizes of the directories.
+fn dir_sizes(fs: &FileSystem) -> impl Iterator<Item = u64> + '_ {
+    let sizes = fs.sizes();
+    (0..fs.num_nodes())
+        .filter(|&node| fs.is_dir(node))
+        .map(move |node| sizes[node])
+}
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    type Input = FileSystem;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        replay(input)
+    }
+
+    fn part1(fs: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let total: u64 = dir_sizes(fs).filter(|&size| size <= 100000).sum();
+        Ok(total.into())
+    }
+
+    fn part2(fs: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let used_space = fs.size(FileSystem::ROOT);
+        let must_delete = used_space.saturating_sub(40000000);
+        let min = dir_sizes(fs).filter(|&size| size >= must_delete).min();
+        min.map(Answer::from)
+            .ok_or_else(|| SolveError::no_solution("no directory is big enough"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_generated};
+    use std::collections::HashMap;
+    use std::fs::read_to_string;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        solve::<Day07>(&read_to_string("./data/example.txt").unwrap(), part)
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(1), Ok(Answer::Int(95437)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(2), Ok(Answer::Int(24933642)));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        // Against adding each file to the directories of its path.
+        for (input, answers) in solve_generated::<Day07>(0..10, 20, &(), &[1, 2]) {
+            let mut sizes: HashMap<Vec<&str>, u64> = HashMap::from([(vec![], 0)]);
+            let mut path = Vec::new();
+            for line in input.lines() {
+                match line.split(' ').collect::<Vec<_>>()[..] {
+                    ["$", "cd", "/"] => path.clear(),
+                    ["$", "cd", ".."] => {
+                        path.pop();
+                    }
+                    ["$", "cd", dir] => path.push(dir),
+                    ["$", "ls"] => (),
+                    ["dir", dir] => {
+                        sizes.insert([&path[..], &[dir]].concat(), 0);
+                    }
+                    [size, _] => {
+                        let size: u64 = size.parse().unwrap();
+                        for depth in 0..=path.len() {
+                            *sizes.get_mut(&path[..depth]).unwrap() += size;
+                        }
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            let part1: u64 = sizes.values().filter(|&&size| size <= 100000).sum();
+            let must_delete = sizes[&vec![]].saturating_sub(40000000);
+            let part2 = sizes
+                .values()
+                .filter(|&&size| size >= must_delete)
+                .min()
+                .unwrap();
+            assert_eq!(answers, [part1.into(), (*part2).into()]);
+        }
+    }
+
+    #[test]
+    fn filesystem() {
+        let fs = Day07::parse(&read_to_string("./data/example.txt").unwrap()).unwrap();
+        let e = fs.lookup("/a/e").unwrap();
+        assert_eq!((fs.path(e), fs.size(e)), ("/a/e".to_string(), 584));
+        assert_eq!(fs.lookup("/a/x"), None);
+        let big: Vec<String> = fs
+            .find(None, 8000000..)
+            .iter()
+            .map(|&n| fs.path(n))
+            .collect();
+        assert_eq!(big, ["/", "/b.txt", "/c.dat", "/d", "/d/d.log"]);
+        assert_eq!(fs.find(Some("i"), ..), [fs.lookup("/a/e/i").unwrap()]);
+        assert_eq!(fs.du(), "584\t/a/e\n94853\t/a\n24933642\t/d\n48381165\t/\n");
+        let tree = fs.tree();
+        assert!(
+            tree.starts_with("- / (dir)\n  - a (dir)\n    - e (dir)\n      - i (file, size=584)\n")
+        );
+    }
+
+    #[test]
+    fn transcripts() {
+        // Absolute paths, revisits and directories entered before being listed.
+        let input = "$ cd a/b\n$ ls\n10 x\n$ cd /\n$ ls\ndir a\n5 y\n$ cd /a\n$ ls\ndir b\n\
+                     $ cd b\n$ ls\n10 x\n$ cd ../..\n$ ls\n5 y\ndir a\n";
+        let fs = replay(input).unwrap();
+        assert_eq!(fs.du(), "10\t/a/b\n10\t/a\n15\t/\n");
+        let error = |input| replay(input).unwrap_err().to_string();
+        assert_eq!(
+            error("$ cd /\n$ rm -r a\n"),
+            "line 2, column 3: unsupported command 'rm'"
+        );
+        assert_eq!(
+            error("$ ls\n10 x\n$ ls\n20 x\n"),
+            "line 4, column 4: 'x' was listed as a directory or with another size"
+        );
+        assert_eq!(
+            error("$ cd ..\n"),
+            "line 1, column 6: the root has no parent"
+        );
+        assert_eq!(
+            error("$ cd a\n10 x\n"),
+            "line 2, column 1: output of a command other than 'ls'"
+        );
+    }
+}
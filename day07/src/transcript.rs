@@ -0,0 +1,94 @@
+use crate::{FileSystem, NodeId};
+use aoc_common::{parse_token, SolveError};
+
+/// Replay a terminal transcript of `cd` and `ls` commands, building the file
+/// system they explored. Directories can be visited and listed in any order
+/// and any number of times, as long as the listings agree.
+pub fn replay(input: &str) -> Result<FileSystem, SolveError> {
+    let mut fs = FileSystem::new();
+    // The directories from the root to the current one.
+    let mut path = vec![FileSystem::ROOT];
+    let mut listing = false;
+    for line in input.lines().filter(|line| !line.trim().is_empty()) {
+        let tokens: Vec<&str> = line.split_ascii_whitespace().collect();
+        match tokens[..] {
+            ["$", "cd", dir] => {
+                cd(&mut fs, &mut path, input, dir)?;
+                listing = false;
+            }
+            ["$", "ls"] => listing = true,
+            ["$", "cd" | "ls", ..] => {
+                return Err(SolveError::at(input, line, "expected 'cd <dir>' or 'ls'"));
+            }
+            ["$", command, ..] => {
+                let message = format!("unsupported command '{}'", command);
+                return Err(SolveError::at(input, command, message));
+            }
+            ["$"] => {
+                let end = &line[line.len()..];
+                return Err(SolveError::at(input, end, "expected a command"));
+            }
+            _ if !listing => {
+                return Err(SolveError::at(
+                    input,
+                    line,
+                    "output of a command other than 'ls'",
+                ));
+            }
+            ["dir", name] => {
+                if fs.add_dir(*path.last().unwrap(), name).is_none() {
+                    let message = format!("'{}' was listed as a file", name);
+                    return Err(SolveError::at(input, name, message));
+                }
+            }
+            [size, name] => {
+                let size = parse_token(input, size)?;
+                if fs.add_file(*path.last().unwrap(), name, size).is_none() {
+                    let message =
+                        format!("'{}' was listed as a directory or with another size", name);
+                    return Err(SolveError::at(input, name, message));
+                }
+            }
+            _ => {
+                return Err(SolveError::at(
+                    input,
+                    line,
+                    "expected 'dir <name>' or '<size> <name>'",
+                ))
+            }
+        }
+    }
+    Ok(fs)
+}
+
+// Change the current directory along `dir`, a path from the root if it starts
+// with '/', creating the directories not listed yet.
+fn cd(
+    fs: &mut FileSystem,
+    path: &mut Vec<NodeId>,
+    input: &str,
+    dir: &str,
+) -> Result<(), SolveError> {
+    if dir.starts_with('/') {
+        path.truncate(1);
+    }
+    for name in dir.split('/') {
+        match name {
+            "" | "." => (),
+            ".." if path.len() == 1 => {
+                return Err(SolveError::at(input, name, "the root has no parent"));
+            }
+            ".." => {
+                path.pop();
+            }
+            _ => match fs.add_dir(*path.last().unwrap(), name) {
+                Some(node) => path.push(node),
+                None => {
+                    let message = format!("'{}' is a file", name);
+                    return Err(SolveError::at(input, name, message));
+                }
+            },
+        }
+    }
+    Ok(())
+}
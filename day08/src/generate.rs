@@ -0,0 +1,22 @@
+use crate::Day08;
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// A square forest of `size` by `size` trees, at least 1.
+impl Generator for Day08 {
+    const SIZE: usize = 99;
+
+    fn generate(rng: &mut StdRng, size: usize, _params: &Self::Params) -> String {
+        let size = size.max(1);
+        (0..size)
+            .map(|_| {
+                let mut row: String = (0..size)
+                    .map(|_| char::from(b'0' + rng.gen_range(0..=9)))
+                    .collect();
+                row.push('\n');
+                row
+            })
+            .collect()
+    }
+}
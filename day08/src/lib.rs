@@ -0,0 +1,118 @@
+use aoc_common::{Answer, Direction, Grid, Point2, SolveError, Solver};
+use std::collections::HashSet;
+
+mod generate;
+
+// Lines of sight from each edge of the map into it, as a start and a direction.
+fn sightlines(map: &Grid<i32>) -> Vec<(Point2, Direction)> {
+    let (width, height) = (map.width() as isize, map.height() as isize);
+    let mut sightlines = Vec::new();
+    for y in 0..height {
+        sightlines.push((Point2::new(0, y), Direction::Right));
+        sightlines.push((Point2::new(width - 1, y), Direction::Left));
+    }
+    for x in 0..width {
+        sightlines.push((Point2::new(x, 0), Direction::Down));
+        sightlines.push((Point2::new(x, height - 1), Direction::Up));
+    }
+    sightlines
+}
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    type Input = Grid<i32>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Grid::parse(input, "a height", |_, char| {
+            char.to_digit(10).map(|height| height as i32)
+        })
+    }
+
+    fn part1(map: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let mut visibles = HashSet::new();
+        for (mut tree, dir) in sightlines(map) {
+            let mut height = -1;
+            while height < 9 && map.contains(tree) {
+                if height < map[tree] {
+                    height = map[tree];
+                    visibles.insert(tree);
+                }
+                tree = tree.step(dir);
+            }
+        }
+        Ok(visibles.len().into())
+    }
+
+    fn part2(map: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let mut scenic = Grid::new(map.width(), map.height(), 1);
+        for (mut tree, dir) in sightlines(map) {
+            let mut dist_to_higher = [0; 10];
+            while map.contains(tree) {
+                // Update score of tree
+                scenic[tree] *= dist_to_higher[map[tree] as usize];
+                // Update distance for next tree
+                for (height, dist) in dist_to_higher.iter_mut().enumerate() {
+                    if height > map[tree] as usize {
+                        *dist += 1;
+                    } else {
+                        *dist = 1;
+                    }
+                }
+                tree = tree.step(dir);
+            }
+        }
+        Ok((*scenic.iter().max().unwrap()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_generated};
+    use std::fs::read_to_string;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        solve::<Day08>(&read_to_string("./data/example.txt").unwrap(), part)
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(1), Ok(Answer::Int(21)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(2), Ok(Answer::Int(8)));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        // Against looking from each tree in the 4 directions.
+        for (input, answers) in solve_generated::<Day08>(0..10, 10, &(), &[1, 2]) {
+            let rows: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+            let (mut visible, mut best) = (0, 0);
+            for (y, row) in rows.iter().enumerate() {
+                for (x, &height) in row.iter().enumerate() {
+                    let lines: [Vec<u8>; 4] = [
+                        row[..x].iter().rev().copied().collect(),
+                        row[x + 1..].to_vec(),
+                        rows[..y].iter().rev().map(|row| row[x]).collect(),
+                        rows[y + 1..].iter().map(|row| row[x]).collect(),
+                    ];
+                    visible += lines.iter().any(|line| line.iter().all(|&h| h < height)) as usize;
+                    let score: usize = lines
+                        .iter()
+                        .map(|line| match line.iter().position(|&h| h >= height) {
+                            Some(blocked) => blocked + 1,
+                            None => line.len(),
+                        })
+                        .product();
+                    best = best.max(score);
+                }
+            }
+            assert_eq!(answers, [visible.into(), best.into()]);
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::Day09;
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// `size` motions of the head.
+impl Generator for Day09 {
+    const SIZE: usize = 2000;
+
+    fn generate(rng: &mut StdRng, size: usize, _params: &Self::Params) -> String {
+        (0..size)
+            .map(|_| {
+                let dir = ['U', 'R', 'D', 'L'][rng.gen_range(0..4)];
+                format!("{} {}\n", dir, rng.gen_range(1..=20))
+            })
+            .collect()
+    }
+}
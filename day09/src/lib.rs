@@ -0,0 +1,173 @@
+use aoc_common::trace::{self, Frame};
+use aoc_common::{parse_lines, parse_token, scan, Answer, Point2, SolveError, Solver};
+use std::collections::HashSet;
+
+mod generate;
+
+enum Dir {
+    U,
+    R,
+    D,
+    L,
+}
+pub struct Move {
+    dir: Dir,
+    len: usize,
+}
+
+impl Move {
+    fn parse(desc: &str) -> Result<Self, SolveError> {
+        let [dir, len] = scan(desc, "{} {}")?;
+        let len: usize = parse_token(desc, len)?;
+        match dir {
+            "U" => Ok(Move { dir: Dir::U, len }),
+            "R" => Ok(Move { dir: Dir::R, len }),
+            "D" => Ok(Move { dir: Dir::D, len }),
+            "L" => Ok(Move { dir: Dir::L, len }),
+            _ => Err(SolveError::at(desc, dir, "expected U, R, D or L")),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+struct Position {
+    x: isize,
+    y: isize,
+}
+
+impl Position {
+    fn mv(&mut self, dir: &Dir) {
+        match dir {
+            Dir::U => self.y += 1,
+            Dir::R => self.x += 1,
+            Dir::D => self.y -= 1,
+            Dir::L => self.x -= 1,
+        }
+    }
+
+    fn follow(&mut self, head: &Position) {
+        let diffx = head.x - self.x;
+        let diffy = head.y - self.y;
+        if diffx.abs() > 1 || diffy.abs() > 1 {
+            self.x += diffx.signum();
+            self.y += diffy.signum();
+        }
+    }
+}
+
+// The visited positions and the knots, the head being H. Up is up.
+fn frame(step: usize, path: &HashSet<Position>, knots: &[Position]) -> Frame {
+    let point = |pos: &Position| Point2::new(pos.x, -pos.y);
+    let knots = knots.iter().enumerate().rev().map(|(i, knot)| match i {
+        0 => (point(knot), 'H'),
+        i => (point(knot), char::from_digit(i as u32, 10).unwrap()),
+    });
+    let path = path.iter().map(|pos| (point(pos), '#'));
+    Frame::from_points(format!("step {}", step), path.chain(knots))
+}
+
+pub struct Day09;
+
+impl Solver for Day09 {
+    type Input = Vec<Move>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_lines(input, Move::parse)
+    }
+
+    fn part1(moves: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let mut path = HashSet::new();
+        let mut head = Position { x: 0, y: 0 };
+        let mut tail = Position { x: 0, y: 0 };
+        let mut step = 0;
+        for mv in moves {
+            for _i in 0..mv.len {
+                head.mv(&mv.dir);
+                tail.follow(&head);
+                path.insert(tail.clone());
+                step += 1;
+                trace::trace(|| frame(step, &path, &[head.clone(), tail.clone()]));
+            }
+        }
+        Ok(path.len().into())
+    }
+
+    fn part2(moves: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let mut path = HashSet::new();
+        let mut knots: Vec<Position> = (0..10).map(|_| Position { x: 0, y: 0 }).collect();
+        let mut step = 0;
+        for mv in moves {
+            for _step in 0..mv.len {
+                knots[0].mv(&mv.dir);
+                for i in 0..9 {
+                    let k = knots[i].clone();
+                    knots[i + 1].follow(&k);
+                }
+                path.insert(knots[9].clone());
+                step += 1;
+                trace::trace(|| frame(step, &path, &knots));
+            }
+        }
+        Ok(path.len().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_generated};
+    use std::fs::read_to_string;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        solve::<Day09>(&read_to_string("./data/example.txt").unwrap(), part)
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(1), Ok(Answer::Int(13)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(2), Ok(Answer::Int(1)));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        // Against knots stepping to their neighbour closest to the knot ahead.
+        let follow = |knot: (isize, isize), ahead: (isize, isize)| {
+            if knot.0.abs_diff(ahead.0) <= 1 && knot.1.abs_diff(ahead.1) <= 1 {
+                return knot;
+            }
+            (-1..=1)
+                .flat_map(|dx| (-1..=1).map(move |dy| (knot.0 + dx, knot.1 + dy)))
+                .min_by_key(|&(x, y)| (x - ahead.0).pow(2) + (y - ahead.1).pow(2))
+                .unwrap()
+        };
+        for (input, answers) in solve_generated::<Day09>(0..10, 100, &(), &[1, 2]) {
+            let visited = |len| {
+                let mut rope = vec![(0, 0); len];
+                let mut visited = HashSet::from([(0, 0)]);
+                for line in input.lines() {
+                    let (dir, steps) = line.split_once(' ').unwrap();
+                    let (dx, dy) = match dir {
+                        "U" => (0, 1),
+                        "R" => (1, 0),
+                        "D" => (0, -1),
+                        _ => (-1, 0),
+                    };
+                    for _ in 0..steps.parse().unwrap() {
+                        rope[0] = (rope[0].0 + dx, rope[0].1 + dy);
+                        for i in 1..len {
+                            rope[i] = follow(rope[i], rope[i - 1]);
+                        }
+                        visited.insert(rope[len - 1]);
+                    }
+                }
+                Answer::from(visited.len())
+            };
+            assert_eq!(answers, [visited(2), visited(10)]);
+        }
+    }
+}
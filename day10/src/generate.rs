@@ -0,0 +1,28 @@
+use crate::Day10;
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// `size` instructions, or more to run for the 240 cycles of the screen.
+impl Generator for Day10 {
+    const SIZE: usize = 140;
+
+    fn generate(rng: &mut StdRng, size: usize, _params: &Self::Params) -> String {
+        let mut input = String::new();
+        let (mut x, mut cycles, mut instructions) = (1, 0, 0);
+        while instructions < size || cycles < 240 {
+            if rng.gen_bool(0.4) {
+                input.push_str("noop\n");
+                cycles += 1;
+            } else {
+                // Keep the sprite on the screen.
+                let value = rng.gen_range(-1..=40) - x;
+                input.push_str(&format!("addx {}\n", value));
+                x += value;
+                cycles += 2;
+            }
+            instructions += 1;
+        }
+        input
+    }
+}
@@ -0,0 +1,148 @@
+use aoc_common::{next_token, parse_lines, parse_token, Answer, SolveError, Solver};
+
+mod generate;
+
+#[derive(Debug)]
+enum Command {
+    Noop,
+    Addx(isize),
+}
+
+impl Command {
+    fn parse(str: &str) -> Result<Self, SolveError> {
+        let mut tokens = str.split_whitespace();
+        match tokens.next() {
+            Some("noop") => Ok(Command::Noop),
+            Some("addx") => Ok(Command::Addx(parse_token(
+                str,
+                next_token(str, tokens.next(), "a value")?,
+            )?)),
+            _ => Err(SolveError::at(
+                str,
+                str.trim_start(),
+                "expected noop or addx",
+            )),
+        }
+    }
+}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Input = Vec<isize>;
+    type Params = ();
+
+    // Value of the register during each cycle.
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let registers = parse_lines(input, Command::parse)?
+            .into_iter()
+            .flat_map(|command| match command {
+                Command::Addx(value) => {
+                    vec![0, value]
+                }
+                Command::Noop => {
+                    vec![0]
+                }
+            })
+            .fold(vec![1, 1], |mut acc, value| {
+                let prev_reg = if let Some(val) = acc.last() { *val } else { 1 };
+                acc.push(prev_reg + value);
+                acc
+            });
+        Ok(registers)
+    }
+
+    fn part1(registers: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let score: isize = registers
+            .iter()
+            .enumerate()
+            .map(|(cycle, reg)| {
+                if (cycle as isize - 20) % 40 == 0 {
+                    cycle as isize * *reg
+                } else {
+                    0
+                }
+            })
+            .sum();
+        Ok(score.into())
+    }
+
+    fn part2(registers: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let mut crt = String::new();
+        let mut reg = registers.iter().skip(1);
+        for i in 1..=240 {
+            let regindex = reg.next().ok_or_else(|| {
+                SolveError::no_solution("the program stops before the screen is drawn")
+            })? + 1;
+            let crtindex = (i - 1) % 40 + 1;
+            if regindex - 1 <= crtindex && crtindex <= regindex + 1 {
+                crt.push('#');
+            } else {
+                crt.push(' ');
+            }
+        }
+        let rows: Vec<String> = (0..6)
+            .map(|i| crt[i * 40..(i + 1) * 40].to_string())
+            .collect();
+        Ok(Answer::Raster(rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_generated};
+    use std::fs::read_to_string;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        solve::<Day10>(&read_to_string("./data/example.txt").unwrap(), part)
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(1), Ok(Answer::Int(13140)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            example(2),
+            Ok(Answer::Raster(vec![
+                "##  ##  ##  ##  ##  ##  ##  ##  ##  ##  ".to_string(),
+                "###   ###   ###   ###   ###   ###   ### ".to_string(),
+                "####    ####    ####    ####    ####    ".to_string(),
+                "#####     #####     #####     #####     ".to_string(),
+                "######      ######      ######      ####".to_string(),
+                "#######       #######       #######     ".to_string(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn generated_inputs() {
+        // Against running the program cycle by cycle.
+        for (input, answers) in solve_generated::<Day10>(0..10, 50, &(), &[1, 2]) {
+            let (mut x, mut cycle, mut strength): (isize, isize, isize) = (1, 0, 0);
+            let mut screen = vec![String::new(); 6];
+            for line in input.lines() {
+                let (cycles, value) = match line.strip_prefix("addx ") {
+                    Some(value) => (2, value.parse().unwrap()),
+                    None => (1, 0),
+                };
+                for _ in 0..cycles {
+                    cycle += 1;
+                    if cycle % 40 == 20 {
+                        strength += cycle * x;
+                    }
+                    if cycle <= 240 {
+                        let column = (cycle - 1) % 40;
+                        let lit = (column - x).abs() <= 1;
+                        screen[(cycle as usize - 1) / 40].push(if lit { '#' } else { ' ' });
+                    }
+                }
+                x += value;
+            }
+            assert_eq!(answers, [strength.into(), Answer::Raster(screen)]);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::Day11;
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const PRIMES: [usize; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+// `size` monkeys, between 2 and 9 so that their divisors are distinct primes.
+// Worry levels are not reduced in part 1, so there is no squaring and monkeys
+// only throw to the ones before them, but for monkey 0: an item is inspected
+// at most twice per round, and stays within bounds for 20 rounds.
+impl Generator for Day11 {
+    const SIZE: usize = 8;
+
+    fn generate(rng: &mut StdRng, size: usize, _params: &Self::Params) -> String {
+        let size = size.clamp(2, PRIMES.len());
+        let mut divisors = PRIMES.to_vec();
+        divisors.shuffle(rng);
+        let monkeys: Vec<String> = (0..size)
+            .map(|i| {
+                let items: Vec<String> = (0..rng.gen_range(1..=6))
+                    .map(|_| rng.gen_range(50..100).to_string())
+                    .collect();
+                let operation = if rng.gen_bool(0.5) {
+                    format!("* {}", rng.gen_range(2..=5))
+                } else {
+                    format!("+ {}", rng.gen_range(1..=8))
+                };
+                let mut target = || match i {
+                    0 => rng.gen_range(1..size),
+                    _ => rng.gen_range(0..i),
+                };
+                format!(
+                    "Monkey {}:
+  Starting items: {}
+  Operation: new = old {}
+  Test: divisible by {}
+    If true: throw to monkey {}
+    If false: throw to monkey {}
+",
+                    i,
+                    items.join(", "),
+                    operation,
+                    divisors[i],
+                    target(),
+                    target()
+                )
+            })
+            .collect();
+        monkeys.join("\n")
+    }
+}
@@ -0,0 +1,256 @@
+use aoc_common::{params, parse_token, scan, Answer, SolveError, Solver};
+
+mod generate;
+
+#[derive(Clone)]
+enum Operation {
+    Mult(Value),
+    Add(Value),
+}
+
+#[derive(Clone)]
+enum Value {
+    Number(usize),
+    Old,
+}
+
+impl Operation {
+    // `op` and `operand` are slices of `input`.
+    fn parse(input: &str, op: &str, operand: &str) -> Result<Self, SolveError> {
+        let value = match operand {
+            "old" => Value::Old,
+            str => Value::Number(parse_token(input, str)?),
+        };
+        match op {
+            "+" => Ok(Operation::Add(value)),
+            "*" => Ok(Operation::Mult(value)),
+            _ => Err(SolveError::at(input, op, "expected + or *")),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    items: Vec<usize>,
+    operation: Operation,
+    test: usize,
+    actions: (usize, usize),
+    inspections: usize,
+}
+
+impl Monkey {
+    // Errors are located in `block`, the lines describing the monkey, one of
+    // `monkeys` in all.
+    fn parse(block: &str, monkeys: usize) -> Result<Self, SolveError> {
+        let [_id, items, op, operand, divisor, iftrue, iffalse] = scan(
+            block,
+            "Monkey {}:
+  Starting items: {}
+  Operation: new = old {} {}
+  Test: divisible by {}
+    If true: throw to monkey {}
+    If false: throw to monkey {}",
+        )?;
+        let items = items
+            .split(", ")
+            .map(|item| parse_token(block, item))
+            .collect::<Result<_, _>>()?;
+        let operation = Operation::parse(block, op, operand)?;
+        let test = parse_token(block, divisor)?;
+        if test == 0 {
+            return Err(SolveError::at(block, divisor, "the divisor cannot be 0"));
+        }
+        let target = |token| match parse_token(block, token)? {
+            monkey if monkey < monkeys => Ok(monkey),
+            _ => Err(SolveError::at(block, token, "no such monkey")),
+        };
+        Ok(Monkey {
+            items,
+            operation,
+            test,
+            actions: (target(iftrue)?, target(iffalse)?),
+            inspections: 0,
+        })
+    }
+
+    fn run(&mut self, worry: usize, divider: Option<usize>) -> Vec<(usize, usize)> {
+        let mut out = vec![];
+        for old in self.items.iter() {
+            self.inspections += 1;
+            let mut new = match self.operation {
+                Operation::Mult(Value::Number(val)) => old * val,
+                Operation::Add(Value::Number(val)) => old + val,
+                Operation::Mult(Value::Old) => old * old,
+                Operation::Add(Value::Old) => old + old,
+            };
+            new /= worry;
+            if let Some(value) = divider {
+                new %= value;
+            }
+            if new % self.test == 0 {
+                out.push((self.actions.0, new));
+            } else {
+                out.push((self.actions.1, new));
+            }
+        }
+        self.items.clear();
+        out.reverse();
+        out
+    }
+}
+
+fn monkey_business(monkeys: &[Monkey]) -> Answer {
+    let mut inspections: Vec<usize> = monkeys.iter().map(|m| m.inspections).collect();
+    inspections.sort();
+    (inspections[inspections.len() - 1] * inspections[inspections.len() - 2]).into()
+}
+
+params! {
+    pub struct Params {
+        rounds1: usize = 20,
+        // Worry levels are divided by this after each inspection in part 1.
+        relief: usize = 3,
+        rounds2: usize = 10000,
+    }
+}
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Input = Vec<Monkey>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let blocks: Vec<&str> = input.trim_end().split("\n\n").collect();
+        let mut monkeys: Vec<Monkey> = vec![];
+        let mut lines = 0;
+        for block in &blocks {
+            let monkey = Monkey::parse(block, blocks.len());
+            monkeys.push(monkey.map_err(|err| err.after_lines(lines))?);
+            lines += block.lines().count() + 1;
+        }
+        if monkeys.len() < 2 {
+            return Err(SolveError::at_end(input, "expected at least two monkeys"));
+        }
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Self::Input, params: &Self::Params) -> Result<Answer, SolveError> {
+        if params.relief == 0 {
+            return Err(SolveError::Param("relief cannot be 0".to_string()));
+        }
+        let mut monkeys = monkeys.clone();
+        for _round in 0..params.rounds1 {
+            for m in 0..monkeys.len() {
+                for (n, item) in monkeys[m].run(params.relief, None).iter() {
+                    monkeys[*n].items.push(*item);
+                }
+            }
+        }
+        Ok(monkey_business(&monkeys))
+    }
+
+    fn part2(monkeys: &Self::Input, params: &Self::Params) -> Result<Answer, SolveError> {
+        let mut monkeys = monkeys.clone();
+        let divider: usize = monkeys
+            .iter()
+            .map(|m| m.test)
+            .reduce(|acc, t| acc * t)
+            .unwrap();
+        for _round in 0..params.rounds2 {
+            for m in 0..monkeys.len() {
+                for (n, item) in monkeys[m].run(1, Some(divider)).iter() {
+                    monkeys[*n].items.push(*item);
+                }
+            }
+        }
+        Ok(monkey_business(&monkeys))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_generated};
+    use std::fs::read_to_string;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        solve::<Day11>(&read_to_string("./data/example.txt").unwrap(), part)
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(1), Ok(Answer::Int(10605)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(2), Ok(Answer::Int(2713310158)));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        // Against following each item on its own, as items do not interact: an
+        // item thrown to a monkey before the current one waits for the next round.
+        let solved = solve_generated::<Day11>(0..10, 4, &Params::default(), &[1, 2]);
+        for (input, answers) in solved {
+            let last = |line: &str| line.rsplit(' ').next().unwrap().to_string();
+            let monkeys: Vec<Vec<&str>> = input
+                .split("\n\n")
+                .map(|block| {
+                    block
+                        .lines()
+                        .map(|line| line.rsplit(": ").next().unwrap())
+                        .collect()
+                })
+                .collect();
+            let divisor = |at: usize| last(monkeys[at][3]).parse::<u64>().unwrap();
+            let modulus: u64 = (0..monkeys.len()).map(divisor).product();
+            let business = |rounds: usize, relief: Option<u64>| {
+                let mut inspections = vec![0_usize; monkeys.len()];
+                for (start, monkey) in monkeys.iter().enumerate() {
+                    for item in monkey[1].split(", ") {
+                        let (mut at, mut worry, mut round) =
+                            (start, item.parse::<u64>().unwrap(), 0);
+                        while round < rounds {
+                            inspections[at] += 1;
+                            let operand = match last(monkeys[at][2]).as_str() {
+                                "old" => worry,
+                                value => value.parse().unwrap(),
+                            };
+                            worry = match monkeys[at][2].contains('*') {
+                                true => worry * operand,
+                                false => worry + operand,
+                            };
+                            worry = match relief {
+                                Some(relief) => worry / relief,
+                                None => worry % modulus,
+                            };
+                            let target = monkeys[at][if worry % divisor(at) == 0 { 4 } else { 5 }];
+                            let target: usize = last(target).parse().unwrap();
+                            round += (target < at) as usize;
+                            at = target;
+                        }
+                    }
+                }
+                inspections.sort_unstable_by(|a, b| b.cmp(a));
+                Answer::from(inspections[0] * inspections[1])
+            };
+            assert_eq!(answers, [business(20, Some(3)), business(10000, None)]);
+        }
+    }
+
+    #[test]
+    fn parse_error() {
+        let input = read_to_string("./data/example.txt").unwrap();
+        let input = input.replacen(
+            "If false: throw to monkey 3",
+            "If false: throw to monkey 4",
+            1,
+        );
+        assert_eq!(
+            Day11::parse(&input).err().unwrap().to_string(),
+            "line 6, column 31: no such monkey"
+        );
+    }
+}
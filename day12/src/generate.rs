@@ -0,0 +1,42 @@
+use crate::Day12;
+use aoc_common::{Generator, Grid, Point2};
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// A `size` x `size / 5` heightmap, at least 26 x 5, with random heights but
+// for a path from S to E climbing at most one step at a time.
+impl Generator for Day12 {
+    const SIZE: usize = 160;
+
+    fn generate(rng: &mut StdRng, size: usize, _params: &Self::Params) -> String {
+        let (width, height) = (size.max(26), (size / 5).max(5));
+        let mut map = Grid::new(width, height, b'a');
+        for p in map.points().collect::<Vec<_>>() {
+            map[p] = rng.gen_range(b'a'..=b'z');
+        }
+        // Go right or down from S on the left edge to E on the right one.
+        let mut pos = Point2::new(0, rng.gen_range(0..height as isize / 2));
+        let end = Point2::new(
+            width as isize - 1,
+            rng.gen_range(height as isize / 2..height as isize),
+        );
+        let mut path = vec![pos];
+        while pos != end {
+            if pos.y == end.y || (pos.x < end.x && rng.gen_bool(0.7)) {
+                pos.x += 1;
+            } else {
+                pos.y += 1;
+            }
+            path.push(pos);
+        }
+        // Climb from a to z, with as many steps at each height.
+        for (i, p) in path.iter().enumerate() {
+            map[*p] = b'a' + (i * 26 / path.len()) as u8;
+        }
+        map[path[0]] = b'S';
+        map[end] = b'E';
+        map.rows()
+            .map(|row| format!("{}\n", String::from_utf8_lossy(row)))
+            .collect()
+    }
+}
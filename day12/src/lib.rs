@@ -0,0 +1,151 @@
+use aoc_common::{Answer, Grid, Point2, SolveError, Solver};
+use pathfinding::directed::astar::astar;
+use pathfinding::directed::dijkstra::dijkstra;
+
+mod generate;
+
+pub struct Input {
+    map: Grid<u8>,
+    start: Point2,
+    end: Point2,
+}
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Input = Input;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let mut start = None;
+        let mut end = None;
+        let map = Grid::parse(input, "a height", |pos, car| match car {
+            'S' => {
+                start = Some(pos);
+                Some(b'a')
+            }
+            'E' => {
+                end = Some(pos);
+                Some(b'z')
+            }
+            'a'..='z' => Some(car as u8),
+            _ => None,
+        })?;
+        match (start, end) {
+            (Some(start), Some(end)) => Ok(Input { map, start, end }),
+            _ => Err(SolveError::at_end(input, "expected a start S and an end E")),
+        }
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let Input { map, start, end } = input;
+        let successors = |pos: &Point2| {
+            let maxheight = map[*pos] + 2;
+            map.neighbours4(*pos)
+                .filter(|next| map[*next] < maxheight)
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+
+        let path = astar(
+            start,
+            successors,
+            |pos| pos.manhattan(*end),
+            |pos| pos == end,
+        );
+        match path {
+            Some((_, steps)) => Ok(steps.into()),
+            None => Err(SolveError::no_solution("the end cannot be reached")),
+        }
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let Input { map, end, .. } = input;
+        let predecessors = |pos: &Point2| {
+            let minheight = map[*pos] - 2;
+            map.neighbours4(*pos)
+                .filter(|prev| minheight < map[*prev])
+                .map(|prev| (prev, 1))
+                .collect::<Vec<_>>()
+        };
+
+        let path = dijkstra(end, predecessors, |pos| map[*pos] == b'a');
+        match path {
+            Some((_, steps)) => Ok(steps.into()),
+            None => Err(SolveError::no_solution(
+                "no square of height a reaches the end",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_generated};
+    use std::collections::{HashMap, VecDeque};
+    use std::fs::read_to_string;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        solve::<Day12>(&read_to_string("./data/example.txt").unwrap(), part)
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(1), Ok(Answer::Int(31)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(2), Ok(Answer::Int(29)));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        // Against a breadth-first search forwards from each start.
+        for (input, answers) in solve_generated::<Day12>(0..10, 30, &(), &[1, 2]) {
+            let rows: Vec<Vec<u8>> = input.lines().map(|line| line.bytes().collect()).collect();
+            let cells = || (0..rows.len()).flat_map(|y| (0..rows[0].len()).map(move |x| (x, y)));
+            let height = |(x, y): (usize, usize)| match rows[y][x] {
+                b'S' => b'a',
+                b'E' => b'z',
+                height => height,
+            };
+            let steps = |start: (usize, usize)| {
+                let mut steps = HashMap::from([(start, 0)]);
+                let mut queue = VecDeque::from([start]);
+                while let Some((x, y)) = queue.pop_front() {
+                    if rows[y][x] == b'E' {
+                        return Some(steps[&(x, y)]);
+                    }
+                    let next = [
+                        (x + 1, y),
+                        (x.wrapping_sub(1), y),
+                        (x, y + 1),
+                        (x, y.wrapping_sub(1)),
+                    ];
+                    for (nx, ny) in next {
+                        if ny < rows.len()
+                            && nx < rows[0].len()
+                            && height((nx, ny)) <= height((x, y)) + 1
+                            && !steps.contains_key(&(nx, ny))
+                        {
+                            steps.insert((nx, ny), steps[&(x, y)] + 1);
+                            queue.push_back((nx, ny));
+                        }
+                    }
+                }
+                None
+            };
+            let start = cells().find(|&(x, y)| rows[y][x] == b'S').unwrap();
+            let part2 = cells()
+                .filter(|&cell| height(cell) == b'a')
+                .filter_map(steps)
+                .min();
+            assert_eq!(
+                answers,
+                [steps(start).unwrap().into(), part2.unwrap().into()]
+            );
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use crate::Day13;
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// `size` pairs of different packets.
+impl Generator for Day13 {
+    const SIZE: usize = 150;
+
+    fn generate(rng: &mut StdRng, size: usize, _params: &Self::Params) -> String {
+        let pairs: Vec<String> = (0..size)
+            .map(|_| {
+                let left = packet(rng, 0);
+                let right = loop {
+                    let right = packet(rng, 0);
+                    if right != left {
+                        break right;
+                    }
+                };
+                format!("{}\n{}\n", left, right)
+            })
+            .collect();
+        pairs.join("\n")
+    }
+}
+
+// A list of `depth` or less nested lists.
+fn packet(rng: &mut StdRng, depth: usize) -> String {
+    let items: Vec<String> = (0..rng.gen_range(0..=5))
+        .map(|_| {
+            if depth < 4 && rng.gen_bool(0.3) {
+                packet(rng, depth + 1)
+            } else {
+                rng.gen_range(0..=10).to_string()
+            }
+        })
+        .collect();
+    format!("[{}]", items.join(","))
+}
@@ -0,0 +1,263 @@
+use aoc_common::{Answer, SolveError, Solver};
+use std::cmp::Ordering;
+
+mod generate;
+
+#[derive(Debug)]
+pub struct Pair {
+    left: Item,
+    right: Item,
+}
+
+impl Pair {
+    fn parse(input: &str, index: &mut usize) -> Result<Self, SolveError> {
+        let left = Item::parse(input, index)?;
+        expect(input, index, b'\n')?;
+        let right = Item::parse(input, index)?;
+        skip_whitespace(input, index);
+        Ok(Pair { left, right })
+    }
+}
+
+// Byte at `index`, or an error if the input ends there.
+fn peek(input: &str, index: usize) -> Result<u8, SolveError> {
+    input
+        .as_bytes()
+        .get(index)
+        .copied()
+        .ok_or_else(|| SolveError::at_end(input, "unexpected end of input"))
+}
+
+fn expect(input: &str, index: &mut usize, byte: u8) -> Result<(), SolveError> {
+    if peek(input, *index)? != byte {
+        let message = format!("expected {:?}", byte as char);
+        return Err(SolveError::at(input, &input[*index..], message));
+    }
+    *index += 1;
+    Ok(())
+}
+
+fn skip_whitespace(input: &str, index: &mut usize) {
+    while input
+        .as_bytes()
+        .get(*index)
+        .is_some_and(u8::is_ascii_whitespace)
+    {
+        *index += 1;
+    }
+}
+
+#[derive(Debug, Clone, Eq)]
+enum Item {
+    List(Vec<Item>),
+    Value(u32),
+}
+
+impl Item {
+    fn parse(input: &str, index: &mut usize) -> Result<Self, SolveError> {
+        match peek(input, *index)? {
+            b'[' => {
+                *index += 1;
+                let mut subitems = Vec::new();
+                while peek(input, *index)? != b']' {
+                    subitems.push(Self::parse(input, index)?);
+                    while peek(input, *index)? == b',' {
+                        *index += 1
+                    }
+                }
+                *index += 1;
+                Ok(Self::List(subitems))
+            }
+            c if c.is_ascii_digit() => {
+                *index += 1;
+                let mut val = (c - b'0') as u32;
+                while let Some(d) = input.as_bytes().get(*index).filter(|d| d.is_ascii_digit()) {
+                    val = 10 * val + (d - b'0') as u32;
+                    *index += 1;
+                }
+                Ok(Self::Value(val))
+            }
+            _ => Err(SolveError::at(
+                input,
+                &input[*index..],
+                "expected a list or a value",
+            )),
+        }
+    }
+}
+
+impl Ord for Item {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Item::List(llist), Item::List(rlist)) => {
+                let (mut liter, mut riter) = (llist.iter(), rlist.iter());
+                loop {
+                    let (litem, ritem) = (liter.next(), riter.next());
+                    match (litem, ritem) {
+                        (Some(l), Some(r)) => {
+                            let s = l.cmp(r);
+                            if s != Ordering::Equal {
+                                return s;
+                            }
+                        }
+                        (Some(_), None) => return Ordering::Greater,
+                        (None, Some(_)) => return Ordering::Less,
+                        (None, None) => return Ordering::Equal,
+                    };
+                }
+            }
+            (Item::List(_), Item::Value(_)) => self.cmp(&Item::List(vec![other.clone()])),
+            (Item::Value(_), Item::List(_)) => Item::List(vec![self.clone()]).cmp(other),
+            (Item::Value(lval), Item::Value(rval)) => {
+                if lval < rval {
+                    Ordering::Less
+                } else if lval == rval {
+                    Ordering::Equal
+                } else {
+                    Ordering::Greater
+                }
+            }
+        }
+    }
+}
+
+impl PartialOrd for Item {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Item {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    type Input = Vec<Pair>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let mut pairs: Vec<Pair> = vec![];
+        let mut index = 0;
+        skip_whitespace(input, &mut index);
+        while index < input.len() {
+            let pair = Pair::parse(input, &mut index)?;
+            pairs.push(pair);
+        }
+        Ok(pairs)
+    }
+
+    fn part1(pairs: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let mut sum = 0;
+        for (i, pair) in pairs.iter().enumerate() {
+            if pair.left.cmp(&pair.right) == Ordering::Less {
+                sum += i + 1
+            };
+        }
+        Ok(sum.into())
+    }
+
+    fn part2(pairs: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let mut packets: Vec<&Item> = pairs
+            .iter()
+            .flat_map(|pair| [&pair.left, &pair.right])
+            .collect();
+        let two = Item::List(vec![Item::List(vec![Item::Value(2)])]);
+        let six = Item::List(vec![Item::List(vec![Item::Value(6)])]);
+        packets.push(&two);
+        packets.push(&six);
+        packets.sort();
+        let itwo = packets
+            .iter()
+            .enumerate()
+            .find(|&(_, &item)| item.cmp(&two) == Ordering::Equal)
+            .unwrap()
+            .0;
+        let isix = packets
+            .iter()
+            .enumerate()
+            .find(|&(_, &item)| item.cmp(&six) == Ordering::Equal)
+            .unwrap()
+            .0;
+        Ok(((itwo + 1) * (isix + 1)).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_generated};
+    use std::fs::read_to_string;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        solve::<Day13>(&read_to_string("./data/example.txt").unwrap(), part)
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(1), Ok(Answer::Int(13)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(2), Ok(Answer::Int(140)));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        // Against comparing the packets token by token, a value facing a list
+        // being wrapped in one, and counting the packets before the dividers.
+        const OPEN: i32 = -1;
+        const CLOSE: i32 = -2;
+        let tokens = |packet: &str| {
+            let spaced = packet
+                .replace('[', " [ ")
+                .replace(']', " ] ")
+                .replace(',', " ");
+            let mut tokens: Vec<i32> = spaced
+                .split_whitespace()
+                .map(|token| match token {
+                    "[" => OPEN,
+                    "]" => CLOSE,
+                    value => value.parse().unwrap(),
+                })
+                .collect();
+            tokens.reverse();
+            tokens
+        };
+        let compare = |left: &str, right: &str| {
+            let (mut left, mut right) = (tokens(left), tokens(right));
+            loop {
+                match (left.pop(), right.pop()) {
+                    (None, None) => return Ordering::Equal,
+                    (a, b) if a == b => (),
+                    (Some(CLOSE), _) => return Ordering::Less,
+                    (_, Some(CLOSE)) => return Ordering::Greater,
+                    (Some(OPEN), Some(value)) => right.extend([CLOSE, value]),
+                    (Some(value), Some(OPEN)) => left.extend([CLOSE, value]),
+                    (a, b) => return a.cmp(&b),
+                }
+            }
+        };
+        for (input, answers) in solve_generated::<Day13>(0..10, 20, &(), &[1, 2]) {
+            let packets: Vec<&str> = input.lines().filter(|line| !line.is_empty()).collect();
+            let part1: usize = packets
+                .chunks(2)
+                .enumerate()
+                .filter(|(_, pair)| compare(pair[0], pair[1]) == Ordering::Less)
+                .map(|(i, _)| i + 1)
+                .sum();
+            let before = |divider| {
+                let before = packets
+                    .iter()
+                    .filter(|p| compare(p, divider) == Ordering::Less);
+                before.count()
+            };
+            let part2 = (before("[[2]]") + 1) * (before("[[6]]") + 2);
+            assert_eq!(answers, [part1.into(), part2.into()]);
+        }
+    }
+}
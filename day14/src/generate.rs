@@ -0,0 +1,30 @@
+use crate::Day14;
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// `size` rock paths below the source of the sand, the deepest at `size` or so.
+impl Generator for Day14 {
+    const SIZE: usize = 150;
+
+    fn generate(rng: &mut StdRng, size: usize, _params: &Self::Params) -> String {
+        let depth = size.max(3) as isize;
+        (0..size)
+            .map(|_| {
+                let (mut x, mut y) = (rng.gen_range(450..=550), rng.gen_range(2..=depth));
+                let mut points = vec![format!("{},{}", x, y)];
+                for _ in 0..rng.gen_range(1..=5) {
+                    let len = rng.gen_range(1..=10);
+                    match rng.gen_range(0..4) {
+                        0 => x += len,
+                        1 => x -= len,
+                        2 => y += len,
+                        _ => y = (y - len).max(2),
+                    }
+                    points.push(format!("{},{}", x, y));
+                }
+                format!("{}\n", points.join(" -> "))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,254 @@
+use aoc_common::trace::{self, Frame};
+use aoc_common::{next_token, parse_lines, parse_token, Answer, Point2, SolveError, Solver};
+use std::collections::HashSet;
+use std::iter::zip;
+
+mod generate;
+
+// Where a unit of sand tries to go, in order.
+const FALLS: [Point2; 3] = [Point2::new(0, 1), Point2::new(-1, 1), Point2::new(1, 1)];
+
+#[derive(Debug)]
+struct Sand {
+    pos: Point2,
+}
+
+impl Sand {
+    fn new() -> Self {
+        Self {
+            pos: Point2::new(500, 0),
+        }
+    }
+
+    // Move one step down if possible.
+    fn fall(&mut self, rocks: &HashSet<Point2>) -> bool {
+        match FALLS
+            .iter()
+            .map(|fall| self.pos + *fall)
+            .find(|next| !rocks.contains(next))
+        {
+            Some(next) => {
+                self.pos = next;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn mv1(&mut self, rocks: &HashSet<Point2>, bottom_rock: isize) -> State {
+        if !self.fall(rocks) {
+            State::Stopped
+        } else if self.pos.y > bottom_rock {
+            State::Freefall
+        } else {
+            State::Fall
+        }
+    }
+
+    fn mv2(&mut self, rocks: &HashSet<Point2>, floor: isize) -> State {
+        if self.pos.y + 1 == floor || !self.fall(rocks) {
+            State::Stopped
+        } else {
+            State::Fall
+        }
+    }
+}
+
+enum State {
+    Freefall,
+    Fall,
+    Stopped,
+}
+
+pub struct Input {
+    rocks: HashSet<Point2>,
+    bottom_rock: isize,
+}
+
+// Points of a wall, consecutive ones being horizontally or vertically aligned.
+fn parse_wall(line: &str) -> Result<Vec<Point2>, SolveError> {
+    let mut wall: Vec<Point2> = Vec::new();
+    for pos in line.split(" -> ") {
+        let mut coord = pos.split(',');
+        let point = Point2::new(
+            parse_token(line, next_token(line, coord.next(), "x")?)?,
+            parse_token(line, next_token(line, coord.next(), "y")?)?,
+        );
+        if let Some(prev) = wall.last() {
+            if prev.x != point.x && prev.y != point.y {
+                return Err(SolveError::at(line, pos, "walls must be straight"));
+            }
+        }
+        wall.push(point);
+    }
+    Ok(wall)
+}
+
+// The rocks of the input and the sand come to rest, below its source.
+fn frame(num_sand: usize, input: &Input, rocks: &HashSet<Point2>) -> Frame {
+    let cells = rocks.iter().map(|pos| match input.rocks.contains(pos) {
+        true => (*pos, '#'),
+        false => (*pos, 'o'),
+    });
+    let source = (Point2::new(500, 0), '+');
+    Frame::from_points(format!("{} units of sand", num_sand), cells.chain([source]))
+}
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    type Input = Input;
+    type Params = ();
+
+    // Store rocks in a hashset
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let walls = parse_lines(input, parse_wall)?;
+        let mut rocks: HashSet<Point2> = HashSet::new();
+        let mut bottom_rock = 0;
+        for wall in walls {
+            for (org, dest) in zip(wall.iter(), wall.iter().skip(1)) {
+                bottom_rock = bottom_rock.max(org.y).max(dest.y);
+                let step = Point2::new((dest.x - org.x).signum(), (dest.y - org.y).signum());
+                let mut rock = *org;
+                rocks.insert(rock);
+                while rock != *dest {
+                    rock += step;
+                    rocks.insert(rock);
+                }
+            }
+        }
+        Ok(Input { rocks, bottom_rock })
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let mut rocks = input.rocks.clone();
+        let mut num_sand = 0;
+        'moresand: loop {
+            // Create a unit of sand and move it down until stopped or free fall.
+            let mut sand = Sand::new();
+            loop {
+                match sand.mv1(&rocks, input.bottom_rock) {
+                    State::Freefall => break 'moresand,
+                    State::Fall => (),
+                    State::Stopped => {
+                        num_sand += 1;
+                        rocks.insert(sand.pos);
+                        trace::trace(|| frame(num_sand, input, &rocks));
+                        // The rocks may hold the sand up to the source.
+                        if sand.pos.y == 0 {
+                            break 'moresand;
+                        } else {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(num_sand.into())
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let mut rocks = input.rocks.clone();
+        let mut num_sand = 0;
+        'moresand: loop {
+            // Create a unit of sand and move it down until stopped
+            let mut sand = Sand::new();
+            loop {
+                match sand.mv2(&rocks, input.bottom_rock + 2) {
+                    State::Freefall => unreachable!("sand cannot fall through the floor"),
+                    State::Fall => (),
+                    State::Stopped => {
+                        num_sand += 1;
+                        rocks.insert(sand.pos);
+                        trace::trace(|| frame(num_sand, input, &rocks));
+                        if sand.pos.y == 0 {
+                            break 'moresand;
+                        } else {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(num_sand.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_generated};
+    use std::fs::read_to_string;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        solve::<Day14>(&read_to_string("./data/example.txt").unwrap(), part)
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(1), Ok(Answer::Int(24)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(2), Ok(Answer::Int(93)));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        // Against filling the cave depth first from the source.
+        for (input, answers) in solve_generated::<Day14>(0..10, 20, &(), &[1, 2]) {
+            let mut rocks = HashSet::new();
+            for line in input.lines() {
+                let points: Vec<(isize, isize)> = line
+                    .split(" -> ")
+                    .map(|point| {
+                        let (x, y) = point.split_once(',').unwrap();
+                        (x.parse().unwrap(), y.parse().unwrap())
+                    })
+                    .collect();
+                for ends in points.windows(2) {
+                    let (xs, ys) = ((ends[0].0, ends[1].0), (ends[0].1, ends[1].1));
+                    for x in xs.0.min(xs.1)..=xs.0.max(xs.1) {
+                        for y in ys.0.min(ys.1)..=ys.0.max(ys.1) {
+                            rocks.insert((x, y));
+                        }
+                    }
+                }
+            }
+            let bottom = rocks.iter().map(|&(_, y)| y).max().unwrap();
+            let mut blocked = rocks.clone();
+            fill(&mut blocked, (500, 0), bottom);
+            let part1 = blocked.len() - rocks.len();
+            // With the floor, each row is the sand of the row above spread by one.
+            let mut sand = HashSet::from([500]);
+            let mut part2 = 1;
+            for y in 1..bottom + 2 {
+                sand = sand
+                    .iter()
+                    .flat_map(|&x| [x - 1, x, x + 1])
+                    .filter(|&x| !rocks.contains(&(x, y)))
+                    .collect();
+                part2 += sand.len();
+            }
+            assert_eq!(answers, [part1.into(), part2.into()]);
+        }
+    }
+
+    // Fill with sand from `pos` down, false if the sand falls past the bottom.
+    fn fill(blocked: &mut HashSet<(isize, isize)>, pos: (isize, isize), bottom: isize) -> bool {
+        if blocked.contains(&pos) {
+            return true;
+        }
+        if pos.1 > bottom {
+            return false;
+        }
+        for dx in [0, -1, 1] {
+            if !fill(blocked, (pos.0 + dx, pos.1 + 1), bottom) {
+                return false;
+            }
+        }
+        blocked.insert(pos);
+        true
+    }
+}
@@ -0,0 +1,44 @@
+use crate::Day15;
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// `size` sensors, and 4 more far on the diagonals of the distress beacon which
+// each cover a quadrant of the search area but for the beacon itself.
+impl Generator for Day15 {
+    const SIZE: usize = 22;
+
+    fn generate(rng: &mut StdRng, size: usize, params: &Self::Params) -> String {
+        let maxcoord = params.maxcoord.max(1);
+        let (x, y) = (rng.gen_range(0..=maxcoord), rng.gen_range(0..=maxcoord));
+        let mut sensors = Vec::new();
+        for (sx, sy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+            sensors.push(((x + sx * maxcoord, y + sy * maxcoord), (x + sx, y)));
+        }
+        while sensors.len() < size + 4 {
+            let sensor = (
+                rng.gen_range(-maxcoord / 4..=maxcoord * 5 / 4),
+                rng.gen_range(-maxcoord / 4..=maxcoord * 5 / 4),
+            );
+            let dist = sensor.0.abs_diff(x) + sensor.1.abs_diff(y);
+            if dist < 2 {
+                continue;
+            }
+            // Closer than the distress beacon.
+            let range = rng.gen_range(1..dist) as isize;
+            let dx = rng.gen_range(0..=range);
+            let mut sign = || if rng.gen_bool(0.5) { 1 } else { -1 };
+            let beacon = (sensor.0 + sign() * dx, sensor.1 + sign() * (range - dx));
+            sensors.push((sensor, beacon));
+        }
+        sensors
+            .iter()
+            .map(|((sx, sy), (bx, by))| {
+                format!(
+                    "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                    sx, sy, bx, by
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,194 @@
+/*
+TODO:
+- have dist stored in sensor
+- organise sensor per region
+- skip cells to end of sensor zone directly
+*/
+
+use aoc_common::{params, parse_lines, parse_token, scan, Answer, SolveError, Solver};
+use std::collections::HashSet;
+
+mod generate;
+#[cfg(test)]
+mod naive;
+
+#[derive(Debug)]
+pub struct Sensor {
+    x: isize,
+    y: isize,
+    closest: (isize, isize),
+    dist: isize,
+}
+
+fn distance(ax: isize, ay: isize, bx: isize, by: isize) -> isize {
+    (ax.abs_diff(bx) + ay.abs_diff(by)) as isize
+}
+
+impl Sensor {
+    fn parse(line: &str) -> Result<Self, SolveError> {
+        let [x, y, beacon_x, beacon_y] = scan(
+            line,
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+        )?;
+        let coord = |field| parse_token::<isize>(line, field);
+        let (x, y) = (coord(x)?, coord(y)?);
+        let (beacon_x, beacon_y) = (coord(beacon_x)?, coord(beacon_y)?);
+        Ok(Sensor {
+            x,
+            y,
+            closest: (beacon_x, beacon_y),
+            dist: distance(x, y, beacon_x, beacon_y),
+        })
+    }
+}
+
+// The example uses linenum=10 and maxcoord=20.
+params! {
+    pub struct Params {
+        // Row checked in part 1.
+        linenum: isize = 2000000,
+        // Upper bound of both coordinates of the distress beacon.
+        maxcoord: isize = 4000000,
+    }
+}
+
+pub struct Day15;
+
+impl Solver for Day15 {
+    type Input = Vec<Sensor>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let sensors = parse_lines(input, Sensor::parse)?;
+        if sensors.is_empty() {
+            return Err(SolveError::at_end(input, "expected at least one sensor"));
+        }
+        Ok(sensors)
+    }
+
+    fn part1(sensors: &Self::Input, params: &Self::Params) -> Result<Answer, SolveError> {
+        let mut devices = HashSet::new();
+        for sensor in sensors {
+            devices.insert(sensor.closest);
+            devices.insert((sensor.x, sensor.y));
+        }
+        let further_closest = sensors.iter().map(|s| s.dist).max().unwrap();
+        let min_x = sensors.iter().map(|s| s.x).min().unwrap() - further_closest - 2;
+        let max_x = sensors.iter().map(|s| s.x).max().unwrap() + further_closest + 2;
+
+        let mut cannot = 0;
+        let y = params.linenum;
+        'nextslot: for x in min_x..=max_x {
+            for sensor in sensors {
+                if distance(sensor.x, sensor.y, x, y) <= sensor.dist && !devices.contains(&(x, y)) {
+                    cannot += 1;
+                    continue 'nextslot;
+                }
+            }
+        }
+        Ok(cannot.into())
+    }
+
+    fn part2(sensors: &Self::Input, params: &Self::Params) -> Result<Answer, SolveError> {
+        let mut beacon = None;
+        let maxcoord = params.maxcoord;
+        'nextline: for y in 0..=maxcoord {
+            let mut x = 0;
+            'nextcell: loop {
+                for sensor in sensors {
+                    // If in range of a sensor, go to next cell out of range
+                    if distance(sensor.x, sensor.y, x, y) <= sensor.dist {
+                        x = sensor.x + sensor.dist + 1 - (sensor.y.abs_diff(y) as isize);
+                        if x <= maxcoord {
+                            continue 'nextcell;
+                        } else {
+                            continue 'nextline;
+                        }
+                    }
+                }
+                // There can be a beacon
+                beacon = Some((x, y));
+                break 'nextline;
+            }
+        }
+        match beacon {
+            Some((dx, dy)) => Ok((4000000 * dx + dy).into()),
+            None => Err(SolveError::no_solution(
+                "every position is covered by a sensor",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{generate, solve_generated, solve_with};
+    use std::fs::read_to_string;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        let params = Params {
+            linenum: 10,
+            maxcoord: 20,
+        };
+        solve_with::<Day15>(
+            &read_to_string("./data/example.txt").unwrap(),
+            part,
+            &params,
+        )
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(1), Ok(Answer::Int(26)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(2), Ok(Answer::Int(56000011)));
+    }
+
+    #[test]
+    fn parse_error() {
+        let first = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\n";
+        let error = Day15::parse(&format!(
+            "{first}Sensor at x=9, y=1a: closest beacon is at x=10, y=16\n"
+        ));
+        assert_eq!(
+            error.err().unwrap().to_string(),
+            "line 2, column 18: expected isize, found '1a'"
+        );
+        let error = Day15::parse(&format!("{first}Beacon at x=9\n"));
+        assert_eq!(
+            error.err().unwrap().to_string(),
+            "line 2, column 1: expected 'Sensor at x='"
+        );
+    }
+
+    #[test]
+    fn generated_inputs() {
+        let params = Params {
+            linenum: 10,
+            maxcoord: 20,
+        };
+        solve_generated::<Day15>(0..10, 3, &params, &[1, 2]);
+    }
+
+    #[test]
+    fn part2_naive() {
+        let params = Params {
+            linenum: 10,
+            maxcoord: 20,
+        };
+        for seed in 0..20 {
+            let sensors = Day15::parse(&generate::<Day15>(seed, 5, &params)).unwrap();
+            let expected = match naive::scan(&sensors, params.maxcoord) {
+                Some((x, y)) => Ok((4000000 * x + y).into()),
+                None => Err(SolveError::no_solution(
+                    "every position is covered by a sensor",
+                )),
+            };
+            assert_eq!(Day15::part2(&sensors, &params), expected, "seed {}", seed);
+        }
+    }
+}
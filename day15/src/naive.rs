@@ -0,0 +1,25 @@
+// Brute force version of part 2, checking every position instead of skipping
+// the ranges of the sensors.
+use crate::{distance, Sensor};
+use std::collections::HashSet;
+
+// The first position, row by row, where there can be a beacon.
+pub fn scan(sensors: &[Sensor], maxcoord: isize) -> Option<(isize, isize)> {
+    let mut devices = HashSet::new();
+    for sensor in sensors {
+        devices.insert(sensor.closest);
+        devices.insert((sensor.x, sensor.y));
+    }
+    for y in 0..=maxcoord {
+        'nextcell: for x in 0..=maxcoord {
+            for sensor in sensors {
+                // If there cannot be a beacon, go to next space.
+                if distance(sensor.x, sensor.y, x, y) <= sensor.dist || devices.contains(&(x, y)) {
+                    continue 'nextcell;
+                }
+            }
+            return Some((x, y));
+        }
+    }
+    None
+}
@@ -0,0 +1,64 @@
+use crate::Day16;
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+// `size` valves, between 2 and 676 for their names to be unique, connected by
+// tunnels. A quarter of them have a flow rate, but no more than 15 as the
+// search grows exponentially with their number.
+impl Generator for Day16 {
+    const SIZE: usize = 60;
+
+    fn generate(rng: &mut StdRng, size: usize, _params: &Self::Params) -> String {
+        let size = size.clamp(2, 26 * 26);
+        let mut names: Vec<String> = ('A'..='Z')
+            .flat_map(|a| ('A'..='Z').map(move |b| format!("{}{}", a, b)))
+            .filter(|name| name != "AA")
+            .collect();
+        names.shuffle(rng);
+        names.truncate(size - 1);
+        names.insert(0, "AA".to_string());
+        // Long corridors branching off each other, with a few loops, as in the
+        // puzzle where walking between valves takes most of the time.
+        let mut tunnels: Vec<Vec<usize>> = vec![Vec::new(); size];
+        let mut connect = |a: usize, b: usize| {
+            if a != b && !tunnels[a].contains(&b) {
+                tunnels[a].push(b);
+                tunnels[b].push(a);
+            }
+        };
+        for valve in 1..size {
+            connect(valve, rng.gen_range(valve.saturating_sub(3)..valve));
+        }
+        for _ in 0..size / 10 {
+            connect(rng.gen_range(0..size), rng.gen_range(0..size));
+        }
+        let mut flows = vec![0; size];
+        let mut others: Vec<usize> = (1..size).collect();
+        others.shuffle(rng);
+        for &valve in others.iter().take((size / 4).clamp(1, 15)) {
+            flows[valve] = rng.gen_range(1..=25);
+        }
+        let mut lines: Vec<String> = (0..size)
+            .map(|valve| {
+                let succ: Vec<&str> = tunnels[valve].iter().map(|&v| &names[v][..]).collect();
+                let (tunnels, lead, valves) = match succ.len() {
+                    1 => ("tunnel", "leads", "valve"),
+                    _ => ("tunnels", "lead", "valves"),
+                };
+                format!(
+                    "Valve {} has flow rate={}; {} {} to {} {}\n",
+                    names[valve],
+                    flows[valve],
+                    tunnels,
+                    lead,
+                    valves,
+                    succ.join(", ")
+                )
+            })
+            .collect();
+        lines.shuffle(rng);
+        lines.concat()
+    }
+}
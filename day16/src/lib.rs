@@ -0,0 +1,308 @@
+use aoc_common::{params, parse_lines, parse_token, scan, Answer, SolveError, Solver};
+use itertools::Itertools;
+use pathfinding::directed::dijkstra::dijkstra_all;
+use std::collections::HashMap;
+
+mod generate;
+#[cfg(test)]
+mod naive;
+
+#[derive(Debug, Clone)]
+struct InputLine<'a> {
+    name: &'a str,
+    flow: usize,
+    succ: Vec<&'a str>,
+}
+
+impl<'a> InputLine<'a> {
+    fn parse(line: &'a str) -> Result<Self, SolveError> {
+        // The ignored fields are the plural endings of "tunnels lead to valves".
+        let [name, flow, _, _, _, succ] = scan(
+            line,
+            "Valve {} has flow rate={}; tunnel{} lead{} to valve{} {}",
+        )?;
+        let flow = parse_token(line, flow)?;
+        let succ = succ.split(", ").collect();
+        Ok(InputLine { name, flow, succ })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Valve {
+    index: usize,
+    oldindex: usize,
+    flow: usize,
+    successors: Vec<(usize, usize)>,
+    name: String,
+}
+
+pub struct Input {
+    reduced: Vec<Valve>,
+    start: usize,
+}
+
+params! {
+    pub struct Params {
+        minutes1: usize = 30,
+        // Time left after teaching the elephant.
+        minutes2: usize = 26,
+        // Most valves with a flow rate in part 2, which keeps the best pressure
+        // of every set of them: a table of 2 to the power of this many entries.
+        valves2: usize = 24,
+    }
+}
+
+// All the valves, with a cost of 1 to walk through each tunnel.
+fn parse_valves(input: &str) -> Result<Vec<Valve>, SolveError> {
+    let inputlines: Vec<InputLine> = parse_lines(input, InputLine::parse)?;
+
+    let name_to_index: HashMap<&str, usize> = inputlines
+        .iter()
+        .enumerate()
+        .map(|(index, inputline)| (inputline.name, index))
+        .collect();
+
+    let mut valves: Vec<Valve> = Vec::new();
+    for (index, inputline) in inputlines.iter().enumerate() {
+        let mut successors = Vec::new();
+        for succname in &inputline.succ {
+            match name_to_index.get(succname) {
+                Some(&succ) => successors.push((succ, 1)),
+                None => {
+                    return Err(SolveError::at(input, succname, "unknown valve"));
+                }
+            }
+        }
+        valves.push(Valve {
+            index,
+            oldindex: 0,
+            flow: inputline.flow,
+            successors,
+            name: inputline.name.to_string(),
+        });
+    }
+    Ok(valves)
+}
+
+pub struct Day16;
+
+impl Solver for Day16 {
+    type Input = Input;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let valves = parse_valves(input)?;
+        let reduced = reduce(&valves);
+        match reduced.iter().find_position(|valve| valve.name == "AA") {
+            Some((start, _)) => Ok(Input { reduced, start }),
+            None => Err(SolveError::at_end(input, "expected a valve AA")),
+        }
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Result<Answer, SolveError> {
+        let mut states = vec![false; input.reduced.len()];
+        let pressure = explore(&input.reduced, &mut states, input.start, 1, params.minutes1);
+        Ok(pressure.into())
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Result<Answer, SolveError> {
+        if params.valves2 >= usize::BITS as usize {
+            let message = format!("valves2 must be less than {}", usize::BITS);
+            return Err(SolveError::Param(message));
+        }
+        if input.reduced.len() > params.valves2 {
+            let message = format!("more than {} valves with a flow rate", params.valves2);
+            return Err(SolveError::no_solution(message));
+        }
+        // Both explorers open disjoint sets of valves: pair the best pressure of
+        // each set with the best of any set of the other valves.
+        let best = pressures(&input.reduced, input.start, params.minutes2);
+        let mut within = best.clone();
+        for bit in 0..input.reduced.len() {
+            for set in 0..within.len() {
+                if set & 1 << bit != 0 {
+                    within[set] = within[set].max(within[set ^ 1 << bit]);
+                }
+            }
+        }
+        let all = best.len() - 1;
+        let pressure = (0..best.len())
+            .map(|set| best[set] + within[all ^ set])
+            .max()
+            .unwrap_or(0);
+        Ok(pressure.into())
+    }
+}
+
+// Best pressure released by a single explorer in `minutes` for each set of
+// opened valves, a bitset of their indices in the reduced graph.
+fn pressures(reduced: &[Valve], start: usize, minutes: usize) -> Vec<usize> {
+    let mut best = vec![0; 1 << reduced.len()];
+    let mut stack = vec![(start, 0, 0usize, 0)];
+    while let Some((pos, time, opened, pressure)) = stack.pop() {
+        best[opened] = best[opened].max(pressure);
+        for &(next, length) in &reduced[pos].successors {
+            let arrival = time + length + 1;
+            if reduced[next].flow == 0 || opened & 1 << next != 0 || arrival >= minutes {
+                continue;
+            }
+            let released = (minutes - arrival) * reduced[next].flow;
+            stack.push((next, arrival, opened | 1 << next, pressure + released));
+        }
+    }
+    best
+}
+
+fn explore(
+    reduced: &Vec<Valve>,
+    states: &mut Vec<bool>,
+    ipos: usize,
+    time: usize,
+    minutes: usize,
+) -> usize {
+    if time > minutes {
+        return 0;
+    }
+    let mut isucc = 0;
+    let mut maxirest = 0;
+    while isucc < reduced[ipos].successors.len() {
+        let (inext, length) = reduced[ipos].successors[isucc];
+        if inext != ipos && !states[inext] {
+            states[inext] = true;
+            maxirest = maxirest.max(explore(reduced, states, inext, time + length + 1, minutes));
+            states[inext] = false;
+        }
+        isucc += 1;
+    }
+    (minutes + 1 - time) * reduced[ipos].flow + maxirest
+}
+
+// Build reduced graph with relevant valves only
+fn reduce(valves: &[Valve]) -> Vec<Valve> {
+    // Init the new graph. The successors will be updated later.
+    let mut reduced: Vec<Valve> = valves
+        .iter()
+        .filter(|valve| valve.name == "AA" || valve.flow != 0)
+        .enumerate()
+        .map(|(index, valve)| Valve {
+            index,
+            oldindex: valve.index,
+            flow: valve.flow,
+            successors: vec![],
+            name: valve.name.clone(),
+        })
+        .collect();
+    // Create old to new index translator
+    let mut old_to_new_index = HashMap::new();
+    for valve in &reduced {
+        old_to_new_index.insert(valve.oldindex, valve.index);
+    }
+    // Update the successors of the new graph
+    for origin in reduced.iter_mut() {
+        let destinations = dijkstra_all(&origin.oldindex, |valve| {
+            valves[*valve].successors.to_owned()
+        });
+        // Add all reached valve to the successors of origin
+        for (ito, (_ipred, cost)) in &destinations {
+            if !old_to_new_index.contains_key(ito) {
+                continue;
+            }
+            origin.successors.push((old_to_new_index[ito], *cost));
+        }
+    }
+    // Sort successor from most to less relevant
+    for i in 0..reduced.len() {
+        let mut successors = reduced[i].successors.clone();
+        successors.sort_by_key(|(succ, length)| length * reduced[*succ].flow);
+        successors.reverse();
+        reduced[i].successors = successors;
+    }
+    reduced
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{generate, solve, solve_generated, solve_with};
+    use std::fs::read_to_string;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        solve::<Day16>(&read_to_string("./data/example.txt").unwrap(), part)
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(1), Ok(Answer::Int(1651)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(2), Ok(Answer::Int(1707)));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        solve_generated::<Day16>(0..3, 10, &Params::default(), &[1, 2]);
+    }
+
+    // The pruned searches against the naive ones, on small random inputs.
+    fn naive_inputs() -> impl Iterator<Item = (u64, Vec<Valve>, Input)> {
+        (0..20).map(|seed| {
+            let text = generate::<Day16>(seed, 16, &Params::default());
+            let input = Day16::parse(&text).unwrap();
+            (seed, parse_valves(&text).unwrap(), input)
+        })
+    }
+
+    #[test]
+    fn part1_naive() {
+        let params = Params::default();
+        for (seed, valves, input) in naive_inputs() {
+            let aa = valves.iter().position(|valve| valve.name == "AA").unwrap();
+            let expected = naive::runthrough(&valves, aa, params.minutes1);
+            let pressure = Day16::part1(&input, &params).unwrap();
+            assert_eq!(pressure, expected.into(), "seed {}", seed);
+            let greedy = naive::greedy(&input.reduced, input.start, params.minutes1);
+            assert!(expected >= greedy, "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn part2_naive() {
+        let params = Params::default();
+        for (seed, _, input) in naive_inputs() {
+            let mut states = vec![false; input.reduced.len()];
+            states[input.start] = true;
+            let (pos, free) = ([input.start; 2], [0; 2]);
+            let expected = naive::explore3(&input.reduced, &mut states, pos, free, params.minutes2);
+            let pressure = Day16::part2(&input, &params).unwrap();
+            assert_eq!(pressure, expected.into(), "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn part2_two_valves() {
+        let input = "Valve AA has flow rate=0; tunnel leads to valve BB\n\
+                     Valve BB has flow rate=5; tunnel leads to valve AA\n";
+        assert_eq!(solve::<Day16>(input, 2), Ok(Answer::Int(120)));
+    }
+
+    #[test]
+    fn part2_too_many_valves() {
+        let input = read_to_string("./data/example.txt").unwrap();
+        let params = Params {
+            valves2: 6,
+            ..Params::default()
+        };
+        let message = "more than 6 valves with a flow rate";
+        let error = solve_with::<Day16>(&input, 2, &params);
+        assert_eq!(error, Err(SolveError::no_solution(message)));
+        let params = Params {
+            valves2: 64,
+            ..Params::default()
+        };
+        let message = "valves2 must be less than 64";
+        let error = solve_with::<Day16>(&input, 2, &params);
+        assert_eq!(error, Err(SolveError::Param(message.to_string())));
+    }
+}
@@ -0,0 +1,107 @@
+// Slow but straightforward solvers, checking the pruned searches of the crate.
+use crate::Valve;
+use std::collections::HashMap;
+
+// Part 1 minute by minute on the whole graph: each minute, either open the
+// current valve or walk through a tunnel.
+pub fn runthrough(valves: &[Valve], start: usize, minutes: usize) -> usize {
+    let bits: HashMap<usize, usize> = valves
+        .iter()
+        .filter(|valve| valve.flow > 0)
+        .enumerate()
+        .map(|(bit, valve)| (valve.index, bit))
+        .collect();
+    go(valves, &bits, &mut HashMap::new(), start, minutes, 0)
+}
+
+// Best pressure released in the `left` minutes from `index`, `opened` being a
+// bitset of the valves with a flow.
+fn go(
+    valves: &[Valve],
+    bits: &HashMap<usize, usize>,
+    memo: &mut HashMap<(usize, usize, u64), usize>,
+    index: usize,
+    left: usize,
+    opened: u64,
+) -> usize {
+    if left == 0 {
+        return 0;
+    }
+    if let Some(&best) = memo.get(&(index, left, opened)) {
+        return best;
+    }
+    let mut best = 0;
+    // Open the valve, it releases pressure from the next minute on.
+    if let Some(&bit) = bits.get(&index) {
+        if opened & 1 << bit == 0 {
+            let opened = opened | 1 << bit;
+            best =
+                (left - 1) * valves[index].flow + go(valves, bits, memo, index, left - 1, opened);
+        }
+    }
+    // Do not open the valve and move.
+    for &(succ, _) in &valves[index].successors {
+        best = best.max(go(valves, bits, memo, succ, left - 1, opened));
+    }
+    memo.insert((index, left, opened), best);
+    best
+}
+
+// Part 1 opening the valves from the biggest to the smallest flow, which is a
+// lower bound of the best pressure.
+pub fn greedy(reduced: &[Valve], start: usize, minutes: usize) -> usize {
+    let mut nonzeros: Vec<&Valve> = reduced.iter().filter(|valve| valve.flow != 0).collect();
+    nonzeros.sort_by_key(|valve| usize::MAX - valve.flow);
+    let mut time = 0;
+    let mut pressure = 0;
+    let mut icurvalve = start;
+    for next in nonzeros {
+        let length = reduced[icurvalve]
+            .successors
+            .iter()
+            .find(|(succ, _)| *succ == next.index)
+            .map(|(_, length)| *length);
+        match length {
+            Some(length) if time + length + 1 < minutes => {
+                time += length + 1;
+                pressure += (minutes - time) * next.flow;
+                icurvalve = next.index;
+            }
+            _ => (),
+        }
+    }
+    pressure
+}
+
+// Part 2 on the reduced graph without any pruning: the first of both explorers
+// to be free either heads to a closed valve and opens it, or stops there.
+pub fn explore3(
+    reduced: &[Valve],
+    states: &mut Vec<bool>,
+    pos: [usize; 2],
+    free: [usize; 2],
+    minutes: usize,
+) -> usize {
+    let who = if free[0] <= free[1] { 0 } else { 1 };
+    let time = free[who];
+    if time >= minutes {
+        return 0;
+    }
+    let mut stopped = free;
+    stopped[who] = minutes;
+    let mut best = explore3(reduced, states, pos, stopped, minutes);
+    for &(inext, length) in &reduced[pos[who]].successors {
+        let arrival = time + length + 1;
+        if states[inext] || arrival >= minutes {
+            continue;
+        }
+        states[inext] = true;
+        let (mut pos, mut free) = (pos, free);
+        pos[who] = inext;
+        free[who] = arrival;
+        let pressure = (minutes - arrival) * reduced[inext].flow;
+        best = best.max(pressure + explore3(reduced, states, pos, free, minutes));
+        states[inext] = false;
+    }
+    best
+}
@@ -0,0 +1,17 @@
+use crate::Day17;
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// A pattern of `size` jets, at least 1.
+impl Generator for Day17 {
+    const SIZE: usize = 10000;
+
+    fn generate(rng: &mut StdRng, size: usize, _params: &Self::Params) -> String {
+        let mut jets: String = (0..size.max(1))
+            .map(|_| if rng.gen_bool(0.5) { '<' } else { '>' })
+            .collect();
+        jets.push('\n');
+        jets
+    }
+}
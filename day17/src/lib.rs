@@ -0,0 +1,298 @@
+use aoc_common::trace::{self, Frame};
+use aoc_common::{params, Answer, Grid, Point2, SolveError, Solver};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+mod generate;
+
+const WIDTH: isize = 7;
+// Height of the top of the tower shown when tracing.
+const FRAME_ROWS: isize = 40;
+
+#[derive(Debug, Clone)]
+enum Form {
+    Hline,
+    Cross,
+    Corner,
+    Vline,
+    Square,
+}
+
+#[derive(Clone)]
+struct Rock {
+    form: Form,
+    pos: Point2,
+}
+
+impl Rock {
+    fn next_rock(num_rock: &mut isize, y: isize) -> Self {
+        *num_rock += 1;
+        let form = match *num_rock % 5 {
+            1 => Form::Hline,
+            2 => Form::Cross,
+            3 => Form::Corner,
+            4 => Form::Vline,
+            0 => Form::Square,
+            _ => panic!("This should not happen!"),
+        };
+        Self {
+            form,
+            pos: Point2::new(2, y),
+        }
+    }
+
+    fn pieces(&self) -> impl Iterator<Item = Point2> + '_ {
+        let offsets: &[(isize, isize)] = match self.form {
+            Form::Hline => &[(0, 0), (1, 0), (2, 0), (3, 0)],
+            Form::Cross => &[(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+            Form::Corner => &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+            Form::Vline => &[(0, 0), (0, 1), (0, 2), (0, 3)],
+            Form::Square => &[(0, 0), (1, 0), (0, 1), (1, 1)],
+        };
+        offsets.iter().map(|&(x, y)| self.pos + Point2::new(x, y))
+    }
+
+    fn moved(&self, offset: Point2) -> Self {
+        Self {
+            form: self.form.clone(),
+            pos: self.pos + offset,
+        }
+    }
+}
+
+// The rocks come to rest, one bit per column for each row from the floor up.
+#[derive(Default)]
+struct Chamber {
+    rows: Vec<u8>,
+}
+
+impl Chamber {
+    fn top_of_stack(&self) -> isize {
+        self.rows.len() as isize
+    }
+
+    fn is_free(&self, p: Point2) -> bool {
+        (0..WIDTH).contains(&p.x)
+            && p.y >= 0
+            && self
+                .rows
+                .get(p.y as usize)
+                .is_none_or(|row| row & 1 << p.x == 0)
+    }
+
+    fn fits(&self, rock: &Rock) -> bool {
+        rock.pieces().all(|piece| self.is_free(piece))
+    }
+
+    fn settle(&mut self, rock: &Rock) {
+        for piece in rock.pieces() {
+            if piece.y >= self.top_of_stack() {
+                self.rows.resize(piece.y as usize + 1, 0);
+            }
+            self.rows[piece.y as usize] |= 1 << piece.x;
+        }
+    }
+
+    // The free cells that rocks can still reach from above, relative to the
+    // top: all that matters of the tower for the rocks to come.
+    fn surface(&self) -> Vec<Point2> {
+        let top = self.top_of_stack();
+        let mut reached: HashSet<Point2> = (0..WIDTH).map(|x| Point2::new(x, top)).collect();
+        let mut queue: VecDeque<Point2> = reached.iter().copied().collect();
+        while let Some(p) = queue.pop_front() {
+            for step in [Point2::new(-1, 0), Point2::new(1, 0), Point2::new(0, -1)] {
+                let next = p + step;
+                if next.y < top && self.is_free(next) && reached.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        let mut surface: Vec<Point2> = reached
+            .into_iter()
+            .map(|p| Point2::new(p.x, top - p.y))
+            .collect();
+        surface.sort_unstable();
+        surface
+    }
+
+    // The top of the tower, with the last rock as '@'.
+    fn frame(&self, num_rock: isize, block: &Rock) -> Frame {
+        let top = self.top_of_stack();
+        let bottom = (top - FRAME_ROWS).max(0);
+        let mut cells = Grid::new(WIDTH as usize, (top - bottom) as usize, '.');
+        for y in bottom..top {
+            for x in 0..WIDTH {
+                if !self.is_free(Point2::new(x, y)) {
+                    cells[Point2::new(x, top - 1 - y)] = '#';
+                }
+            }
+        }
+        for piece in block.pieces() {
+            cells[Point2::new(piece.x, top - 1 - piece.y)] = '@';
+        }
+        Frame::new(format!("rock {}", num_rock), cells)
+    }
+}
+
+// Height of the tower after `rt` rocks. Once the next rock, the next jet and
+// the surface of the tower repeat, so does the growth of the tower, and the
+// cycles are skipped.
+fn tower_height(jets: &[isize], rt: isize) -> isize {
+    let mut chamber = Chamber::default();
+    // The number of rocks and height of the tower by state after a rock.
+    let mut seen: HashMap<(isize, usize, Vec<Point2>), (isize, isize)> = HashMap::new();
+    let mut skipped = None;
+    let mut num_rock = 0;
+    let mut num_jet = 0;
+    while num_rock < rt {
+        let mut rock = Rock::next_rock(&mut num_rock, chamber.top_of_stack() + 3);
+        loop {
+            let pushed = rock.moved(Point2::new(jets[num_jet], 0));
+            num_jet = (num_jet + 1) % jets.len();
+            if chamber.fits(&pushed) {
+                rock = pushed;
+            }
+            let fallen = rock.moved(Point2::new(0, -1));
+            if !chamber.fits(&fallen) {
+                break;
+            }
+            rock = fallen;
+        }
+        chamber.settle(&rock);
+        trace::trace(|| chamber.frame(num_rock, &rock));
+        if skipped.is_none() {
+            let state = (num_rock % 5, num_jet, chamber.surface());
+            let height = chamber.top_of_stack();
+            if let Some((rocks, prev)) = seen.insert(state, (num_rock, height)) {
+                let cycles = (rt - num_rock) / (num_rock - rocks);
+                num_rock += cycles * (num_rock - rocks);
+                skipped = Some(cycles * (height - prev));
+            }
+        }
+    }
+    chamber.top_of_stack() + skipped.unwrap_or(0)
+}
+
+params! {
+    pub struct Params {
+        rocks1: isize = 2022,
+        rocks2: isize = 1000000000000,
+    }
+}
+
+fn check_rocks(rocks: isize) -> Result<isize, SolveError> {
+    if rocks < 1 {
+        return Err(SolveError::Param("at least one rock must fall".to_string()));
+    }
+    Ok(rocks)
+}
+
+pub struct Day17;
+
+impl Solver for Day17 {
+    type Input = Vec<isize>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let pattern = input.trim_end();
+        if pattern.is_empty() {
+            return Err(SolveError::at_end(input, "expected a jet pattern"));
+        }
+        pattern
+            .char_indices()
+            .map(|(i, c)| match c {
+                '<' => Ok(-1),
+                '>' => Ok(1),
+                _ => Err(SolveError::at(input, &pattern[i..], "expected < or >")),
+            })
+            .collect()
+    }
+
+    fn part1(jets: &Self::Input, params: &Self::Params) -> Result<Answer, SolveError> {
+        Ok(tower_height(jets, check_rocks(params.rocks1)?).into())
+    }
+
+    fn part2(jets: &Self::Input, params: &Self::Params) -> Result<Answer, SolveError> {
+        Ok(tower_height(jets, check_rocks(params.rocks2)?).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_generated};
+    use std::fs::read_to_string;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        solve::<Day17>(&read_to_string("./data/example.txt").unwrap(), part)
+    }
+
+    #[test]
+    fn part1_example() {
+        let jets = read_to_string("./data/example.txt").unwrap();
+        assert_eq!(drop_rocks(&jets, 2022), 3068);
+        assert_eq!(example(1), Ok(Answer::Int(3068)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(2), Ok(Answer::Int(1514285714288)));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        // Against dropping every rock, with few enough rocks in part 2 to do so
+        // but more than it takes to extrapolate the height.
+        let params = Params {
+            rocks1: 2022,
+            rocks2: 5000,
+        };
+        for (input, answers) in solve_generated::<Day17>(0..3, 50, &params, &[1, 2]) {
+            let expected = [2022, 5000].map(|rocks| Answer::from(drop_rocks(&input, rocks)));
+            assert_eq!(answers, expected);
+        }
+    }
+
+    // Height of the tower after `rocks` rocks, each moved one cell at a time.
+    fn drop_rocks(jets: &str, rocks: usize) -> usize {
+        const SHAPES: [&[(usize, usize)]; 5] = [
+            &[(0, 0), (1, 0), (2, 0), (3, 0)],
+            &[(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+            &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+            &[(0, 0), (0, 1), (0, 2), (0, 3)],
+            &[(0, 0), (1, 0), (0, 1), (1, 1)],
+        ];
+        let jets = jets.trim_end().as_bytes();
+        let mut cells = HashSet::new();
+        let (mut height, mut jet) = (0, 0);
+        for rock in 0..rocks {
+            let shape = SHAPES[rock % 5];
+            let fits = |x: usize, y: usize| {
+                let cell = |&(dx, dy): &(usize, usize)| (x + dx, y + dy);
+                shape
+                    .iter()
+                    .map(cell)
+                    .all(|(x, y)| x < 7 && !cells.contains(&(x, y)))
+            };
+            let (mut x, mut y): (usize, usize) = (2, height + 3);
+            loop {
+                let pushed = match jets[jet % jets.len()] {
+                    b'<' => x.checked_sub(1),
+                    _ => Some(x + 1),
+                };
+                jet += 1;
+                if let Some(pushed) = pushed.filter(|&pushed| fits(pushed, y)) {
+                    x = pushed;
+                }
+                if y == 0 || !fits(x, y - 1) {
+                    break;
+                }
+                y -= 1;
+            }
+            for &(dx, dy) in shape {
+                cells.insert((x + dx, y + dy));
+                height = height.max(y + dy + 1);
+            }
+        }
+        height
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{Day18, MAX};
+use aoc_common::{Generator, Point3};
+use rand::rngs::StdRng;
+use rand::seq::IteratorRandom;
+use rand::Rng;
+use std::collections::HashSet;
+
+// A droplet of `size` cubes, grown from a random one within the space, which
+// holds up to 9261 of them.
+impl Generator for Day18 {
+    const SIZE: usize = 2000;
+
+    fn generate(rng: &mut StdRng, size: usize, _params: &Self::Params) -> String {
+        let max = MAX as isize;
+        let within = |p: &Point3| [p.x, p.y, p.z].iter().all(|c| (0..=max).contains(c));
+        let size = size.min((MAX + 1).pow(3));
+        let mut coord = || rng.gen_range(0..=max);
+        let first = Point3::new(coord(), coord(), coord());
+        let mut cubes = vec![first];
+        let mut droplet = HashSet::from([first]);
+        while cubes.len() < size {
+            let cube = cubes[rng.gen_range(0..cubes.len())];
+            let free = cube
+                .neighbours6()
+                .filter(|p| within(p) && !droplet.contains(p))
+                .choose(rng);
+            if let Some(p) = free {
+                droplet.insert(p);
+                cubes.push(p);
+            }
+        }
+        cubes
+            .iter()
+            .map(|p| format!("{},{},{}\n", p.x, p.y, p.z))
+            .collect()
+    }
+}
@@ -0,0 +1,171 @@
+use aoc_common::{parse_lines, parse_token, Answer, Point3, SolveError, Solver};
+use std::collections::HashSet;
+
+mod generate;
+
+const MAX: usize = 20;
+
+struct Space {
+    vol: [[[bool; MAX + 4]; MAX + 4]; MAX + 4],
+}
+
+impl Space {
+    fn new() -> Self {
+        Space {
+            vol: [[[false; MAX + 4]; MAX + 4]; MAX + 4],
+        }
+    }
+
+    fn set(&mut self, p: Point3, val: bool) {
+        self.vol[(p.x + 2) as usize][(p.y + 2) as usize][(p.z + 2) as usize] = val;
+    }
+
+    fn from_cubes(cubes: &[Point3]) -> Self {
+        let mut space = Space::new();
+        for cube in cubes {
+            space.set(*cube, true);
+        }
+        space
+    }
+
+    fn get(&self, p: Point3) -> bool {
+        self.vol[(p.x + 2) as usize][(p.y + 2) as usize][(p.z + 2) as usize]
+    }
+
+    fn within(&self, p: Point3) -> bool {
+        [p.x, p.y, p.z]
+            .iter()
+            .all(|coord| -1 <= *coord && *coord <= MAX as isize + 1)
+    }
+}
+
+// Three coordinates within the space.
+fn parse_cube(line: &str) -> Result<Point3, SolveError> {
+    let mut cube = Vec::new();
+    for token in line.split(',') {
+        let coord: isize = parse_token(line, token)?;
+        if !(0..=MAX as isize).contains(&coord) {
+            let message = format!("coordinates must be between 0 and {}", MAX);
+            return Err(SolveError::at(line, token, message));
+        }
+        cube.push(coord);
+    }
+    match cube[..] {
+        [x, y, z] => Ok(Point3::new(x, y, z)),
+        _ => Err(SolveError::at(line, line, "expected three coordinates")),
+    }
+}
+
+pub struct Day18;
+
+impl Solver for Day18 {
+    type Input = Vec<Point3>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_lines(input, parse_cube)
+    }
+
+    fn part1(cubes: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let space = Space::from_cubes(cubes);
+        let opensides = cubes
+            .iter()
+            .flat_map(|cube| cube.neighbours6())
+            .filter(|side| !space.get(*side))
+            .count();
+        Ok(opensides.into())
+    }
+
+    fn part2(cubes: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let space = Space::from_cubes(cubes);
+        let mut open = Space::new();
+        setopen2(&mut open, &space);
+
+        let opensides = cubes
+            .iter()
+            .flat_map(|cube| cube.neighbours6())
+            .filter(|side| !space.get(*side) && open.get(*side))
+            .count();
+        Ok(opensides.into())
+    }
+}
+
+fn setopen2(open: &mut Space, space: &Space) {
+    let origin = Point3::new(0, 0, 0);
+    let mut todo = vec![origin];
+    let mut done = HashSet::new();
+    open.set(origin, true);
+    while let Some(p) = todo.pop() {
+        done.insert(p);
+        for next in p.neighbours6() {
+            if space.within(next) && !done.contains(&next) && !space.get(next) {
+                open.set(next, true);
+                todo.push(next);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_generated};
+    use std::fs::read_to_string;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        solve::<Day18>(&read_to_string("./data/example.txt").unwrap(), part)
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(1), Ok(Answer::Int(64)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(2), Ok(Answer::Int(58)));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        // Against counting the pairs of touching cubes for part 1, and searching
+        // a way out of the bounding box from each side for part 2.
+        for (input, answers) in solve_generated::<Day18>(0..10, 200, &(), &[1, 2]) {
+            let cubes = Day18::parse(&input).unwrap();
+            let touching = cubes
+                .iter()
+                .flat_map(|a| cubes.iter().map(move |b| (a, b)))
+                .filter(|(a, b)| (a.x - b.x).abs() + (a.y - b.y).abs() + (a.z - b.z).abs() == 1)
+                .count();
+            let droplet: HashSet<Point3> = cubes.iter().copied().collect();
+            let outside = |p: &Point3| {
+                [p.x, p.y, p.z]
+                    .iter()
+                    .any(|c| !(0..=MAX as isize).contains(c))
+            };
+            let escapes = |start: Point3| {
+                let mut seen = HashSet::from([start]);
+                let mut todo = vec![start];
+                while let Some(p) = todo.pop() {
+                    if outside(&p) {
+                        return true;
+                    }
+                    for next in p.neighbours6() {
+                        if !droplet.contains(&next) && seen.insert(next) {
+                            todo.push(next);
+                        }
+                    }
+                }
+                false
+            };
+            let sides = cubes.iter().flat_map(|cube| cube.neighbours6());
+            let exterior = sides
+                .filter(|side| !droplet.contains(side) && escapes(*side))
+                .count();
+            assert_eq!(
+                answers,
+                [(6 * cubes.len() - touching).into(), exterior.into()]
+            );
+        }
+    }
+}
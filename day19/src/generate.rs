@@ -0,0 +1,29 @@
+use crate::Day19;
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// `size` blueprints, or as many as part 2 uses.
+impl Generator for Day19 {
+    const SIZE: usize = 30;
+
+    fn generate(rng: &mut StdRng, size: usize, params: &Self::Params) -> String {
+        (1..=size.max(params.blueprints2))
+            .map(|id| {
+                format!(
+                    "Blueprint {}: Each ore robot costs {} ore. \
+                     Each clay robot costs {} ore. \
+                     Each obsidian robot costs {} ore and {} clay. \
+                     Each geode robot costs {} ore and {} obsidian.\n",
+                    id,
+                    rng.gen_range(2..=4),
+                    rng.gen_range(2..=4),
+                    rng.gen_range(2..=4),
+                    rng.gen_range(5..=20),
+                    rng.gen_range(2..=4),
+                    rng.gen_range(5..=20)
+                )
+            })
+            .collect()
+    }
+}
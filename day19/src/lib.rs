@@ -0,0 +1,297 @@
+use aoc_common::{params, parse_lines, parse_token, scan, Answer, SolveError, Solver};
+
+mod generate;
+
+enum Robot {
+    Ore,
+    Clay,
+    Obs,
+    Geo,
+}
+
+#[derive(Debug)]
+pub struct Blueprint {
+    id: isize,
+    ore_robot_in_ore: isize,
+    clay_robot_in_ore: isize,
+    obs_robot_in_ore: isize,
+    obs_robot_in_clay: isize,
+    geo_robot_in_ore: isize,
+    geo_robot_in_obs: isize,
+}
+
+impl Blueprint {
+    fn parse(line: &str) -> Result<Self, SolveError> {
+        let [id, ore, clay, obs_ore, obs_clay, geo_ore, geo_obs] = scan(
+            line,
+            "Blueprint {}: Each ore robot costs {} ore. \
+             Each clay robot costs {} ore. \
+             Each obsidian robot costs {} ore and {} clay. \
+             Each geode robot costs {} ore and {} obsidian.",
+        )?;
+        let value = |field| parse_token::<isize>(line, field);
+        Ok(Blueprint {
+            id: value(id)?,
+            ore_robot_in_ore: value(ore)?,
+            clay_robot_in_ore: value(clay)?,
+            obs_robot_in_ore: value(obs_ore)?,
+            obs_robot_in_clay: value(obs_clay)?,
+            geo_robot_in_ore: value(geo_ore)?,
+            geo_robot_in_obs: value(geo_obs)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+struct State {
+    min: isize,
+    maxtime: isize,
+    ore: isize,
+    clay: isize,
+    obs: isize,
+    geo: isize,
+    n_ore_robot: isize,
+    n_clay_robot: isize,
+    n_obs_robot: isize,
+    n_geo_robot: isize,
+}
+
+impl State {
+    fn new(maxtime: isize) -> Self {
+        State {
+            min: 0,
+            maxtime,
+            ore: 0,
+            clay: 0,
+            obs: 0,
+            geo: 0,
+            n_ore_robot: 1,
+            n_clay_robot: 0,
+            n_obs_robot: 0,
+            n_geo_robot: 0,
+        }
+    }
+
+    fn next(&mut self) {
+        self.min += 1;
+        self.ore += self.n_ore_robot;
+        self.clay += self.n_clay_robot;
+        self.obs += self.n_obs_robot;
+        self.geo += self.n_geo_robot;
+    }
+
+    fn buy(&mut self, robot: Robot, blueprint: &Blueprint) {
+        loop {
+            if self.min == self.maxtime {
+                return;
+            }
+            match robot {
+                Robot::Ore => {
+                    if self.ore >= blueprint.ore_robot_in_ore {
+                        self.ore -= blueprint.ore_robot_in_ore;
+                        self.next();
+                        self.n_ore_robot += 1;
+                        break;
+                    }
+                }
+                Robot::Clay => {
+                    if self.ore >= blueprint.clay_robot_in_ore {
+                        self.ore -= blueprint.clay_robot_in_ore;
+                        self.next();
+                        self.n_clay_robot += 1;
+                        break;
+                    }
+                }
+                Robot::Obs => {
+                    if self.ore >= blueprint.obs_robot_in_ore
+                        && self.clay >= blueprint.obs_robot_in_clay
+                    {
+                        self.ore -= blueprint.obs_robot_in_ore;
+                        self.clay -= blueprint.obs_robot_in_clay;
+                        self.next();
+                        self.n_obs_robot += 1;
+                        break;
+                    }
+                }
+                Robot::Geo => {
+                    if self.ore >= blueprint.geo_robot_in_ore
+                        && self.obs >= blueprint.geo_robot_in_obs
+                    {
+                        self.ore -= blueprint.geo_robot_in_ore;
+                        self.obs -= blueprint.geo_robot_in_obs;
+                        self.next();
+                        self.n_geo_robot += 1;
+                        break;
+                    }
+                }
+            }
+            self.next();
+        }
+    }
+
+    fn explore(&self, blueprint: &Blueprint) -> isize {
+        let mut best = 0;
+        self.search(blueprint, &mut best);
+        best
+    }
+
+    // Raise `best` to the most geodes that can be opened from this state.
+    fn search(&self, blueprint: &Blueprint, best: &mut isize) {
+        let left = self.maxtime - self.min;
+        // Even with a new geode robot every minute, `best` cannot be beaten.
+        if self.geo + self.n_geo_robot * left + left * (left - 1) / 2 <= *best {
+            return;
+        }
+        *best = (*best).max(self.geo + self.n_geo_robot * left);
+        if left <= 0 {
+            return;
+        }
+        // A robot can only be built once a minute, so there is no use in
+        // producing more of a resource than the most expensive robot needs.
+        let max_ore = blueprint
+            .ore_robot_in_ore
+            .max(blueprint.clay_robot_in_ore)
+            .max(blueprint.obs_robot_in_ore)
+            .max(blueprint.geo_robot_in_ore);
+        let mut robots = vec![];
+        if self.n_obs_robot > 0 {
+            robots.push(Robot::Geo);
+        }
+        if self.n_clay_robot > 0 && self.n_obs_robot < blueprint.geo_robot_in_obs {
+            robots.push(Robot::Obs);
+        }
+        if self.n_clay_robot < blueprint.obs_robot_in_clay {
+            robots.push(Robot::Clay);
+        }
+        if self.n_ore_robot < max_ore {
+            robots.push(Robot::Ore);
+        }
+        for robot in robots {
+            let mut next = self.clone();
+            next.buy(robot, blueprint);
+            next.search(blueprint, best);
+        }
+    }
+}
+
+params! {
+    pub struct Params {
+        minutes1: isize = 24,
+        minutes2: isize = 32,
+        // Number of blueprints left after the elephants ate the others.
+        blueprints2: usize = 3,
+    }
+}
+
+pub struct Day19;
+
+impl Solver for Day19 {
+    type Input = Vec<Blueprint>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_lines(input, Blueprint::parse)
+    }
+
+    fn part1(blueprints: &Self::Input, params: &Self::Params) -> Result<Answer, SolveError> {
+        let state = State::new(params.minutes1);
+        let res: isize = blueprints
+            .iter()
+            .map(|blueprint| blueprint.id * state.explore(blueprint))
+            .sum();
+        Ok(res.into())
+    }
+
+    fn part2(blueprints: &Self::Input, params: &Self::Params) -> Result<Answer, SolveError> {
+        let state = State::new(params.minutes2);
+        let res = blueprints
+            .iter()
+            .take(params.blueprints2)
+            .map(|blueprint| state.explore(blueprint))
+            .reduce(|acc, val| acc * val);
+        match res {
+            Some(res) => Ok(res.into()),
+            None => Err(SolveError::no_solution("there are no blueprints")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_generated};
+    use std::fs::read_to_string;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        solve::<Day19>(&read_to_string("./data/example.txt").unwrap(), part)
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(1), Ok(Answer::Int(33)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(2), Ok(Answer::Int(3472)));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        // Against trying every robot to build next, without the bound on the
+        // geodes, in fewer minutes for it to be fast enough.
+        let params = Params {
+            minutes1: 22,
+            minutes2: 24,
+            blueprints2: 3,
+        };
+        for (input, answers) in solve_generated::<Day19>(0..3, 2, &params, &[1, 2]) {
+            let geodes = |blueprint: &Blueprint, minutes| {
+                let costs = [
+                    [blueprint.ore_robot_in_ore, 0, 0],
+                    [blueprint.clay_robot_in_ore, 0, 0],
+                    [blueprint.obs_robot_in_ore, blueprint.obs_robot_in_clay, 0],
+                    [blueprint.geo_robot_in_ore, 0, blueprint.geo_robot_in_obs],
+                ];
+                most_geodes(&costs, [1, 0, 0, 0], [0; 4], minutes)
+            };
+            let blueprints = Day19::parse(&input).unwrap();
+            let part1: isize = blueprints.iter().map(|b| b.id * geodes(b, 22)).sum();
+            let part2: isize = blueprints.iter().take(3).map(|b| geodes(b, 24)).product();
+            assert_eq!(answers, [part1.into(), part2.into()]);
+        }
+    }
+
+    // Most geodes opened in the minutes `left`, building each next robot as
+    // soon as there is enough of every resource for it, but never more robots
+    // of a resource than the most any robot costs of it.
+    fn most_geodes(
+        costs: &[[isize; 3]; 4],
+        robots: [isize; 4],
+        stock: [isize; 4],
+        left: isize,
+    ) -> isize {
+        let mut best = stock[3] + robots[3] * left;
+        for (robot, cost) in costs.iter().enumerate() {
+            if robot < 3 && robots[robot] >= costs.iter().map(|cost| cost[robot]).max().unwrap() {
+                continue;
+            }
+            let mut wait = 0;
+            while wait < left && (0..3).any(|r| stock[r] + robots[r] * wait < cost[r]) {
+                wait += 1;
+            }
+            // A robot built in the last minute opens nothing.
+            if wait + 1 >= left {
+                continue;
+            }
+            let mut next = stock;
+            for r in 0..4 {
+                next[r] += robots[r] * (wait + 1) - cost.get(r).unwrap_or(&0);
+            }
+            let mut more = robots;
+            more[robot] += 1;
+            best = best.max(most_geodes(costs, more, next, left - wait - 1));
+        }
+        best
+    }
+}
@@ -0,0 +1,25 @@
+use crate::Day20;
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// `size` numbers, at least 2, exactly one of them being 0.
+impl Generator for Day20 {
+    const SIZE: usize = 5000;
+
+    fn generate(rng: &mut StdRng, size: usize, _params: &Self::Params) -> String {
+        let mut numbers: Vec<isize> = (1..size.max(2))
+            .map(|_| loop {
+                let number = rng.gen_range(-10000..=10000);
+                if number != 0 {
+                    break number;
+                }
+            })
+            .collect();
+        numbers.insert(rng.gen_range(0..=numbers.len()), 0);
+        numbers
+            .iter()
+            .map(|number| format!("{}\n", number))
+            .collect()
+    }
+}
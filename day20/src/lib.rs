@@ -0,0 +1,133 @@
+use aoc_common::{params, parse_lines, parse_token, Answer, SolveError, Solver};
+use std::collections::VecDeque;
+
+mod generate;
+
+#[derive(Debug)]
+struct Number {
+    index: usize,
+    value: isize,
+}
+
+fn decrypt(values: &[isize], key: isize, rounds: usize) -> isize {
+    let mut numbers: VecDeque<Number> = values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| Number {
+            index: i,
+            value: key * value,
+        })
+        .collect();
+    for _ in 0..rounds {
+        for index in 0..numbers.len() {
+            shift(&mut numbers, index);
+        }
+    }
+    let izero = numbers
+        .iter()
+        .enumerate()
+        .find(|(_, n)| n.value == 0)
+        .unwrap()
+        .0;
+    (1..=3)
+        .map(|i| numbers[(izero + i * 1000).rem_euclid(numbers.len())].value)
+        .sum()
+}
+
+// Decryption parameters of part 2, part 1 mixes once without a key.
+params! {
+    pub struct Params {
+        key: isize = 811589153,
+        rounds: usize = 10,
+    }
+}
+
+pub struct Day20;
+
+impl Solver for Day20 {
+    type Input = Vec<isize>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let values = parse_lines(input, |line| parse_token(line, line))?;
+        if values.iter().filter(|&&value| value == 0).count() != 1 {
+            return Err(SolveError::at_end(input, "expected exactly one 0"));
+        }
+        Ok(values)
+    }
+
+    fn part1(values: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        Ok(decrypt(values, 1, 1).into())
+    }
+
+    fn part2(values: &Self::Input, params: &Self::Params) -> Result<Answer, SolveError> {
+        Ok(decrypt(values, params.key, params.rounds).into())
+    }
+}
+
+fn shift(numbers: &mut VecDeque<Number>, index: usize) {
+    while numbers[0].index != index {
+        numbers.rotate_left(1);
+    }
+    let number = numbers.pop_front().unwrap();
+    if number.value > 0 {
+        numbers.rotate_left((number.value as usize).rem_euclid(numbers.len()));
+    } else if number.value < 0 {
+        numbers.rotate_right((-number.value as usize).rem_euclid(numbers.len()));
+    }
+    numbers.push_front(number);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_generated};
+    use std::fs::read_to_string;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        solve::<Day20>(&read_to_string("./data/example.txt").unwrap(), part)
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(1), Ok(Answer::Int(3)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(2), Ok(Answer::Int(1623178306)));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        // Against moving each number by swapping it with the next one, as many
+        // times as its value modulo the other numbers.
+        let solved = solve_generated::<Day20>(0..10, 50, &Params::default(), &[1, 2]);
+        for (input, answers) in solved {
+            let mix = |key: isize, rounds: usize| {
+                let values: Vec<isize> = input
+                    .lines()
+                    .map(|v| key * v.parse::<isize>().unwrap())
+                    .collect();
+                let n = values.len();
+                let mut order: Vec<usize> = (0..n).collect();
+                for _ in 0..rounds {
+                    for (number, value) in values.iter().enumerate() {
+                        let mut at = order.iter().position(|&i| i == number).unwrap();
+                        for _ in 0..value.rem_euclid(n as isize - 1) {
+                            order.swap(at, (at + 1) % n);
+                            at = (at + 1) % n;
+                        }
+                    }
+                }
+                let zero = order.iter().position(|&i| values[i] == 0).unwrap();
+                let sum: isize = [1000, 2000, 3000]
+                    .iter()
+                    .map(|k| values[order[(zero + k) % n]])
+                    .sum();
+                Answer::from(sum)
+            };
+            assert_eq!(answers, [mix(1, 1), mix(811589153, 10)]);
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use crate::Day21;
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashSet;
+
+// Build the monkeys yelling `value`, `monkeys` of them, and return the name
+// of the top one. All values are positive and divisions are exact.
+fn expression(
+    rng: &mut StdRng,
+    value: isize,
+    monkeys: usize,
+    names: &mut HashSet<String>,
+    lines: &mut Vec<String>,
+    leaves: &mut Vec<usize>,
+) -> String {
+    let name = loop {
+        let name: String = (0..4).map(|_| rng.gen_range('a'..='z')).collect();
+        if name != "root" && name != "humn" && names.insert(name.clone()) {
+            break name;
+        }
+    };
+    let divisor = (2..=10).find(|d| value % d == 0 && rng.gen_bool(0.5));
+    let (left, op, right) = match rng.gen_range(0..4) {
+        _ if monkeys < 3 => {
+            leaves.push(lines.len());
+            lines.push(format!("{}: {}", name, value));
+            return name;
+        }
+        0 if value > 1 => {
+            let left = rng.gen_range(1..value);
+            (left, '+', value - left)
+        }
+        1 if value < 1_000_000 => {
+            let right = rng.gen_range(2..=10);
+            (value * right, '/', right)
+        }
+        2 if divisor.is_some() => (value / divisor.unwrap(), '*', divisor.unwrap()),
+        _ => {
+            let right = rng.gen_range(1..=100);
+            (value + right, '-', right)
+        }
+    };
+    // Split the other monkeys between both sides, keeping an odd count on each.
+    let count = 2 * rng.gen_range(0..(monkeys - 1) / 2) + 1;
+    let left = expression(rng, left, count, names, lines, leaves);
+    let right = expression(rng, right, monkeys - 1 - count, names, lines, leaves);
+    lines.push(format!("{}: {} {} {}", name, left, op, right));
+    name
+}
+
+// `size` monkeys, at least 3, with an odd count so that every operation has
+// its two operands. humn yells in the expression of one side of root, the
+// other side yielding the same value.
+impl Generator for Day21 {
+    const SIZE: usize = 2001;
+
+    fn generate(rng: &mut StdRng, size: usize, _params: &Self::Params) -> String {
+        let size = size.max(3) | 1;
+        let value = rng.gen_range(1..=1000);
+        let (mut names, mut lines, mut leaves) = (HashSet::new(), Vec::new(), Vec::new());
+        let count = 2 * rng.gen_range(0..(size - 1) / 2) + 1;
+        let mut left = expression(rng, value, count, &mut names, &mut lines, &mut leaves);
+        let humn = leaves[rng.gen_range(0..leaves.len())];
+        let mut right = expression(
+            rng,
+            value,
+            size - 1 - count,
+            &mut names,
+            &mut lines,
+            &mut leaves,
+        );
+        if rng.gen_bool(0.5) {
+            std::mem::swap(&mut left, &mut right);
+        }
+        let op = ['+', '-', '*', '/'][rng.gen_range(0..4)];
+        lines.push(format!("root: {} {} {}", left, op, right));
+        // Rename the chosen leaf humn, names being only used as whole words.
+        let name = lines[humn][..4].to_string();
+        for line in lines.iter_mut() {
+            *line = line.replace(&name, "humn");
+        }
+        lines.shuffle(rng);
+        lines.iter().map(|line| format!("{}\n", line)).collect()
+    }
+}
@@ -0,0 +1,274 @@
+use aoc_common::{next_token, parse_lines, parse_token, Answer, SolveError, Solver};
+use std::collections::HashMap;
+
+mod generate;
+
+#[derive(Debug, Clone)]
+pub enum Operation {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Operation {
+    fn parse(line: &str, token: &str) -> Result<Self, SolveError> {
+        match token {
+            "+" => Ok(Self::Add),
+            "-" => Ok(Self::Sub),
+            "*" => Ok(Self::Mul),
+            "/" => Ok(Self::Div),
+            _ => Err(SolveError::at(line, token, "expected +, -, * or /")),
+        }
+    }
+
+    fn process(&self, left: isize, right: isize) -> Result<isize, SolveError> {
+        let res = match self {
+            Operation::Add => left.checked_add(right),
+            Operation::Sub => left.checked_sub(right),
+            Operation::Mul => left.checked_mul(right),
+            Operation::Div if right == 0 => {
+                return Err(SolveError::no_solution("division by zero"))
+            }
+            Operation::Div => left.checked_div(right),
+        };
+        res.ok_or_else(|| SolveError::no_solution("overflow"))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Monkey {
+    Op {
+        left: String,
+        op: Operation,
+        right: String,
+    },
+    Value {
+        val: isize,
+    },
+    Unknown,
+}
+
+impl Monkey {
+    fn parse(line: &str) -> Result<(String, Self), SolveError> {
+        let tokens: Vec<&str> = line.split_ascii_whitespace().collect();
+        let name = next_token(line, tokens.first().copied(), "a name")?;
+        let name = name.trim_end_matches(':').to_owned();
+        match tokens.len() {
+            2 => Ok((
+                name,
+                Monkey::Value {
+                    val: parse_token(line, tokens[1])?,
+                },
+            )),
+            4 => Ok((
+                name,
+                Monkey::Op {
+                    left: tokens[1].to_owned(),
+                    op: Operation::parse(line, tokens[2])?,
+                    right: tokens[3].to_owned(),
+                },
+            )),
+            _ => Err(SolveError::at(
+                line,
+                line,
+                "expected a value or an operation",
+            )),
+        }
+    }
+
+    // None if the monkey waits for the unknown.
+    fn eval(&self, monkeys: &HashMap<String, Monkey>) -> Result<Option<isize>, SolveError> {
+        match self {
+            Monkey::Value { val } => Ok(Some(*val)),
+            Monkey::Op { left, op, right } => {
+                let lres = monkeys[left].eval(monkeys)?;
+                let rres = monkeys[right].eval(monkeys)?;
+                match (lres, rres) {
+                    (Some(lvalue), Some(rvalue)) => op.process(lvalue, rvalue).map(Some),
+                    _ => Ok(None),
+                }
+            }
+            Monkey::Unknown => Ok(None),
+        }
+    }
+
+    // None if the unknown is on both sides or none of them.
+    fn solve(
+        &self,
+        res: isize,
+        monkeys: &HashMap<String, Monkey>,
+    ) -> Result<Option<isize>, SolveError> {
+        match self {
+            Monkey::Op { left, op, right } => {
+                match (monkeys[left].eval(monkeys)?, monkeys[right].eval(monkeys)?) {
+                    (Some(lval), None) => {
+                        let rres = match op {
+                            Operation::Add => Operation::Sub.process(res, lval),
+                            Operation::Sub => Operation::Sub.process(lval, res),
+                            Operation::Mul => Operation::Div.process(res, lval),
+                            Operation::Div => Operation::Div.process(lval, res),
+                        }?;
+                        monkeys[right].solve(rres, monkeys)
+                    }
+                    (None, Some(rval)) => {
+                        let lres = match op {
+                            Operation::Add => Operation::Sub.process(res, rval),
+                            Operation::Sub => Operation::Add.process(rval, res),
+                            Operation::Mul => Operation::Div.process(res, rval),
+                            Operation::Div => Operation::Mul.process(rval, res),
+                        }?;
+                        monkeys[left].solve(lres, monkeys)
+                    }
+                    _ => Ok(None),
+                }
+            }
+            Monkey::Unknown => Ok(Some(res)),
+            Monkey::Value { .. } => Ok(None),
+        }
+    }
+}
+
+pub struct Day21;
+
+impl Solver for Day21 {
+    type Input = HashMap<String, Monkey>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let monkeys: HashMap<String, Monkey> =
+            parse_lines(input, Monkey::parse)?.into_iter().collect();
+        for name in ["root", "humn"] {
+            if !monkeys.contains_key(name) {
+                let message = format!("expected a monkey named {}", name);
+                return Err(SolveError::at_end(input, message));
+            }
+        }
+        for (name, monkey) in &monkeys {
+            if let Monkey::Op { left, right, .. } = monkey {
+                for operand in [left, right] {
+                    if !monkeys.contains_key(operand) {
+                        return Err(SolveError::no_solution(format!(
+                            "{} waits for an unknown monkey {}",
+                            name, operand
+                        )));
+                    }
+                }
+            }
+        }
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        match monkeys["root"].eval(monkeys)? {
+            Some(res) => Ok(res.into()),
+            None => Err(SolveError::no_solution("root cannot be evaluated")),
+        }
+    }
+
+    fn part2(monkeys: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let mut monkeys = monkeys.clone();
+        let root = monkeys.remove("root").unwrap();
+        if let Monkey::Op { left, op: _, right } = root {
+            monkeys.insert(
+                "root".to_owned(),
+                Monkey::Op {
+                    left,
+                    op: Operation::Sub,
+                    right,
+                },
+            );
+        }
+        monkeys.remove("humn").unwrap();
+        monkeys.insert("humn".to_owned(), Monkey::Unknown);
+
+        match monkeys["root"].solve(0, &monkeys)? {
+            Some(res) => Ok(res.into()),
+            None => Err(SolveError::no_solution(
+                "humn must appear once in the equation",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_generated};
+    use std::fs::read_to_string;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        solve::<Day21>(&read_to_string("./data/example.txt").unwrap(), part)
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(1), Ok(Answer::Int(152)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(2), Ok(Answer::Int(301)));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        // Against evaluating the monkeys that can be in passes until all are,
+        // and both sides of root being equal once humn yells the answer.
+        for (input, answers) in solve_generated::<Day21>(0..10, 31, &(), &[1, 2]) {
+            let jobs: HashMap<&str, Vec<&str>> = input
+                .lines()
+                .map(|line| {
+                    let (name, job) = line.split_once(": ").unwrap();
+                    (name, job.split(' ').collect())
+                })
+                .collect();
+            let yells = |humn: isize| {
+                let mut values = HashMap::from([("humn", humn)]);
+                while values.len() < jobs.len() {
+                    for (name, job) in &jobs {
+                        let value = match job[..] {
+                            [value] => value.parse().unwrap(),
+                            [a, op, b] if values.contains_key(a) && values.contains_key(b) => {
+                                let (a, b) = (values[a], values[b]);
+                                match op {
+                                    "+" => a + b,
+                                    "-" => a - b,
+                                    "*" => a * b,
+                                    _ => a / b,
+                                }
+                            }
+                            _ => continue,
+                        };
+                        values.entry(name).or_insert(value);
+                    }
+                }
+                values
+            };
+            let humn = jobs["humn"][0].parse().unwrap();
+            assert_eq!(answers[0], yells(humn)["root"].into());
+            let Answer::Int(humn) = answers[1] else {
+                panic!("{:?} is not a number", answers[1]);
+            };
+            let values = yells(humn as isize);
+            assert_eq!(values[jobs["root"][0]], values[jobs["root"][2]]);
+        }
+    }
+
+    #[test]
+    fn division_by_zero() {
+        let input = "root: humn / zero\nhumn: 5\nzero: 0\n";
+        let error = Err(SolveError::no_solution("division by zero"));
+        assert_eq!(solve::<Day21>(input, 1), error);
+        // Solving for humn divides by the monkey it is multiplied by.
+        let input = "root: half + five\nhalf: humn * zero\nzero: 0\nfive: 5\nhumn: 1\n";
+        assert_eq!(solve::<Day21>(input, 2), error);
+    }
+
+    #[test]
+    fn overflow() {
+        let input = format!("root: big * big\nbig: {}\nhumn: 1\n", isize::MAX);
+        let error = Err(SolveError::no_solution("overflow"));
+        assert_eq!(solve::<Day21>(&input, 1), error);
+    }
+}
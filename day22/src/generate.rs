@@ -0,0 +1,73 @@
+use crate::Day22;
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// The 11 nets of a cube, '#' marking the faces.
+const NETS: [&[&str]; 11] = [
+    &["#   ", "####", "#   "],
+    &["#   ", "####", " #  "],
+    &["#   ", "####", "  # "],
+    &["#   ", "####", "   #"],
+    &[" #  ", "####", " #  "],
+    &[" #  ", "####", "  # "],
+    &["##  ", " ###", " #  "],
+    &["##  ", " ###", "  # "],
+    &["##  ", " ###", "   #"],
+    &["##  ", " ## ", "  ##"],
+    &["###  ", "  ###"],
+];
+
+const FACE: usize = 50;
+
+// A board folding into a cube of 50 tiles wide faces, from a random net
+// flipped or rotated, with a few walls, then a path of `size` moves.
+impl Generator for Day22 {
+    const SIZE: usize = 2000;
+
+    fn generate(rng: &mut StdRng, size: usize, _params: &Self::Params) -> String {
+        let net = NETS[rng.gen_range(0..NETS.len())];
+        let mut faces: Vec<Vec<bool>> = net
+            .iter()
+            .map(|row| row.chars().map(|c| c == '#').collect())
+            .collect();
+        if rng.gen_bool(0.5) {
+            faces = (0..faces[0].len())
+                .map(|x| faces.iter().map(|row| row[x]).collect())
+                .collect();
+        }
+        if rng.gen_bool(0.5) {
+            faces.reverse();
+        }
+        if rng.gen_bool(0.5) {
+            faces.iter_mut().for_each(|row| row.reverse());
+        }
+        let mut board = String::new();
+        for y in 0..faces.len() * FACE {
+            let mut row: String = (0..faces[0].len() * FACE)
+                .map(|x| match faces[y / FACE][x / FACE] {
+                    false => ' ',
+                    true if rng.gen_bool(0.1) => '#',
+                    true => '.',
+                })
+                .collect();
+            if y == 0 {
+                // The start must be an open tile.
+                if let Some(x) = row.find(|c| c != ' ') {
+                    row.replace_range(x..=x, ".");
+                }
+            }
+            board.push_str(row.trim_end());
+            board.push('\n');
+        }
+        board.push('\n');
+        for i in 0..size.max(1) {
+            if i > 0 {
+                board.push(if rng.gen_bool(0.5) { 'L' } else { 'R' });
+            }
+            board.push_str(&rng.gen_range(1..=50).to_string());
+        }
+        board.push('\n');
+        board
+    }
+}
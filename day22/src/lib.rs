@@ -0,0 +1,669 @@
+use aoc_common::{parse_token, Answer, Direction, Grid, Point2, SolveError, Solver};
+use std::collections::{HashMap, HashSet};
+
+mod generate;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Tile {
+    Empty,
+    Slot,
+    Wall,
+}
+
+impl Tile {
+    fn parse(car: char) -> Option<Self> {
+        match car {
+            ' ' => Some(Self::Empty),
+            '#' => Some(Self::Wall),
+            '.' => Some(Self::Slot),
+            _ => None,
+        }
+    }
+}
+
+trait Maze {
+    fn get_tile(&self, at: Point2) -> Tile;
+    fn next_pos(&self, pos: &Pos) -> Result<Pos, SolveError>;
+}
+
+#[derive(Debug, Clone)]
+struct Pos {
+    at: Point2,
+    dir: Direction,
+}
+
+impl Pos {
+    fn execute(&mut self, command: &Command, maze: &dyn Maze) -> Result<(), SolveError> {
+        match command {
+            Command::TurnLeft => self.dir = self.dir.turn_left(),
+            Command::TurnRight => self.dir = self.dir.turn_right(),
+            Command::MoveOn(len) => {
+                for _ in 0..*len {
+                    let next_pos = maze.next_pos(self)?;
+                    if maze.get_tile(next_pos.at) == Tile::Wall {
+                        break;
+                    }
+                    *self = next_pos;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+struct Board {
+    tiles: Grid<Tile>,
+    hdims: Vec<(isize, isize)>,
+    vdims: Vec<(isize, isize)>,
+}
+
+impl Board {
+    fn parse(input: &str) -> Result<Self, SolveError> {
+        // The board ends with the first empty line.
+        let board = match input.find("\n\n") {
+            Some(end) => &input[..end],
+            None => input,
+        };
+        let tiles = Grid::parse_padded(board, "' ', '#' or '.'", Tile::Empty, |_, car| {
+            Tile::parse(car)
+        })?;
+        // Retrieve min/max position of each row
+        let mut hdims: Vec<(isize, isize)> = Vec::new();
+        for (y, row) in tiles.rows().enumerate() {
+            let mut filled = row
+                .iter()
+                .enumerate()
+                .filter(|(_, tile)| **tile != Tile::Empty);
+            let first = match filled.next() {
+                Some((x, _)) => x as isize,
+                None => {
+                    let line = input.lines().nth(y).unwrap();
+                    return Err(SolveError::at(line, line, "expected a tile").after_lines(y));
+                }
+            };
+            let last = filled.next_back().map_or(first, |(x, _)| x as isize);
+            hdims.push((first, last));
+        }
+        // Retrieve min/max position of each col
+        let mut vdims: Vec<(isize, isize)> = Vec::new();
+        for x in 0..tiles.width() as isize {
+            let mut filled =
+                (0..tiles.height() as isize).filter(|y| tiles[Point2::new(x, *y)] != Tile::Empty);
+            let first = match filled.next() {
+                Some(y) => y,
+                None => {
+                    let message = format!("column {} has no tile", x + 1);
+                    return Err(SolveError::at(input, input, message));
+                }
+            };
+            let last = filled.next_back().unwrap_or(first);
+            vdims.push((first, last));
+        }
+        Ok(Self {
+            tiles,
+            hdims,
+            vdims,
+        })
+    }
+}
+
+impl Maze for Board {
+    fn get_tile(&self, at: Point2) -> Tile {
+        self.tiles[at]
+    }
+
+    fn next_pos(&self, pos: &Pos) -> Result<Pos, SolveError> {
+        let Point2 { x, y } = pos.at;
+        let (hdims, vdims) = (self.hdims[y as usize], self.vdims[x as usize]);
+        // Wrap around to the other side of the row or column.
+        let at = match pos.dir {
+            Direction::Up if y == vdims.0 => Point2::new(x, vdims.1),
+            Direction::Down if y == vdims.1 => Point2::new(x, vdims.0),
+            Direction::Right if x == hdims.1 => Point2::new(hdims.0, y),
+            Direction::Left if x == hdims.0 => Point2::new(hdims.1, y),
+            dir => pos.at.step(dir),
+        };
+        Ok(Pos { at, dir: pos.dir })
+    }
+}
+
+#[derive(Debug)]
+enum Command {
+    TurnRight,
+    TurnLeft,
+    MoveOn(isize),
+}
+
+impl Command {
+    fn parse(line: &str, token: &str) -> Result<Command, SolveError> {
+        match token {
+            "R" => Ok(Command::TurnRight),
+            "L" => Ok(Command::TurnLeft),
+            _ => Ok(Command::MoveOn(parse_token(line, token)?)),
+        }
+    }
+}
+
+// Front/Back, Top/Bottom, Left/Right
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum CornerId {
+    FTL,
+    FTR,
+    FBL,
+    FBR,
+    BTL,
+    BTR,
+    BBL,
+    BBR,
+}
+
+#[derive(Clone, Debug)]
+struct Face {
+    id: FaceId,
+    dir: Direction,
+    at: Point2,
+    // Width of the face in tiles.
+    size: isize,
+}
+
+impl Face {
+    // The corners of the face, on the edges of its tiles. They are counted in
+    // half tiles, so that the centers of the tiles are at odd coordinates and
+    // even a face of a single tile has four distinct corners.
+    fn corners(&self) -> HashMap<CornerId, Point2> {
+        let (at, size) = (self.at * 2, self.size * 2);
+        let coords = [
+            at + Point2::new(size, 0),
+            at + Point2::new(size, size),
+            at + Point2::new(0, size),
+            at,
+        ];
+        let start = self.dir as usize;
+        let ids = match self.id {
+            FaceId::Front => [CornerId::FTL, CornerId::FTR, CornerId::FBR, CornerId::FBL],
+            FaceId::Top => [CornerId::BTL, CornerId::BTR, CornerId::FTR, CornerId::FTL],
+            FaceId::Back => [CornerId::BBL, CornerId::BBR, CornerId::BTR, CornerId::BTL],
+            FaceId::Bottom => [CornerId::FBL, CornerId::FBR, CornerId::BBR, CornerId::BBL],
+            FaceId::Left => [CornerId::BTL, CornerId::FTL, CornerId::FBL, CornerId::BBL],
+            FaceId::Right => [CornerId::FTR, CornerId::BTR, CornerId::BBR, CornerId::FBR],
+        };
+        (0..4).map(|i| (ids[i], coords[(i + start) % 4])).collect()
+    }
+
+    fn contains(&self, at: Point2) -> bool {
+        let end = self.at + Point2::new(self.size, self.size);
+        self.at.x <= at.x && at.x < end.x && self.at.y <= at.y && at.y < end.y
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+enum FaceId {
+    Top,
+    Bottom,
+    Front,
+    Back,
+    Left,
+    Right,
+}
+
+struct Cube<'a> {
+    board: &'a Board,
+    faces: HashMap<FaceId, Face>,
+}
+
+impl<'a> Cube<'a> {
+    fn new(board: &'a Board) -> Result<Self, SolveError> {
+        // The six faces hold all the tiles.
+        let tiles = board
+            .tiles
+            .iter()
+            .filter(|tile| **tile != Tile::Empty)
+            .count();
+        let size = (tiles / 6).isqrt();
+        let (width, height) = (board.tiles.width(), board.tiles.height());
+        if size == 0 || 6 * size * size != tiles || width % size != 0 || height % size != 0 {
+            return Err(SolveError::no_solution(
+                "the board does not fold into a cube",
+            ));
+        }
+        // Find 'front'
+        let front = Face {
+            id: FaceId::Front,
+            dir: Direction::Up,
+            at: Point2::new(board.hdims[0].0, 0),
+            size: size as isize,
+        };
+        // Build list of square in the map
+        let mut regions: HashMap<Point2, bool> = HashMap::new();
+        for y in (0..height as isize).step_by(size) {
+            for x in (0..=board.hdims[y as usize].1).step_by(size) {
+                let at = Point2::new(x, y);
+                match board.tiles[at] {
+                    Tile::Empty => {}
+                    Tile::Slot | Tile::Wall => {
+                        regions.insert(at, false);
+                    }
+                }
+            }
+        }
+        // Associate square to Faces
+        let numregions = regions.len();
+        let mut faces = HashMap::from([(front.id.clone(), front.clone())]);
+        Self::map_faces(&mut regions, &front, &mut faces);
+        // Each square must be a different face, next to 4 others.
+        let folded = numregions == 6
+            && faces.len() == 6
+            && faces.values().all(|face| {
+                Direction::ALL.iter().all(|&dir| {
+                    let (id, _) = Self::neighbour(face.id.clone(), face.dir, dir);
+                    faces.contains_key(&id)
+                })
+            });
+        if !folded {
+            return Err(SolveError::no_solution(
+                "the board does not fold into a cube",
+            ));
+        }
+        Ok(Self { board, faces })
+    }
+
+    fn map_faces(
+        regions: &mut HashMap<Point2, bool>,
+        face: &Face,
+        faces: &mut HashMap<FaceId, Face>,
+    ) {
+        for dir in [
+            Direction::Down,
+            Direction::Up,
+            Direction::Left,
+            Direction::Right,
+        ] {
+            // Compute new position
+            let at = face.at + dir.offset() * face.size;
+            // Is it a region that hasn't been visited yet?
+            if at.x < 0 || at.y < 0 {
+                continue;
+            }
+            match regions.get(&at) {
+                None => continue,
+                Some(visited) if *visited => continue,
+                _ => (),
+            }
+            regions.insert(at, true);
+            // Identify the face.
+            let (neighbour_id, neighbour_dir) = Self::neighbour(face.id.clone(), face.dir, dir);
+            // Store mapped face
+            let neighbour = Face {
+                id: neighbour_id.clone(),
+                dir: neighbour_dir,
+                at,
+                size: face.size,
+            };
+            faces.insert(neighbour_id.clone(), neighbour.clone());
+            // Recurse.
+            Self::map_faces(regions, &neighbour, faces);
+        }
+    }
+
+    fn neighbour(
+        faceid: FaceId,
+        orientation: Direction,
+        direction: Direction,
+    ) -> (FaceId, Direction) {
+        match orientation {
+            Direction::Right => {
+                let (nfaceid, norientation) =
+                    Self::neighbour(faceid, orientation.turn_left(), direction.turn_left());
+                (nfaceid, norientation.turn_right())
+            }
+            Direction::Left => {
+                let (nfaceid, norientation) =
+                    Self::neighbour(faceid, orientation.turn_right(), direction.turn_right());
+                (nfaceid, norientation.turn_left())
+            }
+            Direction::Down => {
+                let (nfaceid, norientation) =
+                    Self::neighbour(faceid, orientation.u_turn(), direction.u_turn());
+                (nfaceid, norientation.u_turn())
+            }
+            Direction::Up => match (faceid, direction) {
+                (FaceId::Front, Direction::Up) => (FaceId::Top, Direction::Up),
+                (FaceId::Front, Direction::Right) => (FaceId::Right, Direction::Up),
+                (FaceId::Front, Direction::Down) => (FaceId::Bottom, Direction::Up),
+                (FaceId::Front, Direction::Left) => (FaceId::Left, Direction::Up),
+                (FaceId::Bottom, Direction::Up) => (FaceId::Front, Direction::Up),
+                (FaceId::Bottom, Direction::Right) => (FaceId::Right, Direction::Right),
+                (FaceId::Bottom, Direction::Down) => (FaceId::Back, Direction::Up),
+                (FaceId::Bottom, Direction::Left) => (FaceId::Left, Direction::Left),
+                (FaceId::Back, Direction::Up) => (FaceId::Bottom, Direction::Up),
+                (FaceId::Back, Direction::Right) => (FaceId::Right, Direction::Down),
+                (FaceId::Back, Direction::Down) => (FaceId::Top, Direction::Up),
+                (FaceId::Back, Direction::Left) => (FaceId::Left, Direction::Down),
+                (FaceId::Top, Direction::Up) => (FaceId::Back, Direction::Up),
+                (FaceId::Top, Direction::Right) => (FaceId::Right, Direction::Left),
+                (FaceId::Top, Direction::Down) => (FaceId::Front, Direction::Up),
+                (FaceId::Top, Direction::Left) => (FaceId::Left, Direction::Right),
+                (FaceId::Left, Direction::Up) => (FaceId::Top, Direction::Left),
+                (FaceId::Left, Direction::Right) => (FaceId::Front, Direction::Up),
+                (FaceId::Left, Direction::Down) => (FaceId::Bottom, Direction::Right),
+                (FaceId::Left, Direction::Left) => (FaceId::Back, Direction::Down),
+                (FaceId::Right, Direction::Up) => (FaceId::Top, Direction::Right),
+                (FaceId::Right, Direction::Right) => (FaceId::Back, Direction::Down),
+                (FaceId::Right, Direction::Down) => (FaceId::Bottom, Direction::Left),
+                (FaceId::Right, Direction::Left) => (FaceId::Front, Direction::Up),
+            },
+        }
+    }
+
+    fn get_face(&self, at: Point2) -> Option<&Face> {
+        self.faces.values().find(|face| face.contains(at))
+    }
+}
+
+impl Maze for Cube<'_> {
+    fn get_tile(&self, at: Point2) -> Tile {
+        self.board.get_tile(at)
+    }
+
+    fn next_pos(&self, pos: &Pos) -> Result<Pos, SolveError> {
+        let next = pos.at.step(pos.dir);
+        let (hdims, vdims) = (
+            self.board.hdims[pos.at.y as usize],
+            self.board.vdims[pos.at.x as usize],
+        );
+
+        // if the position is still on a tile, keep going
+        if hdims.0 <= next.x && next.x <= hdims.1 && vdims.0 <= next.y && next.y <= vdims.1 {
+            return Ok(Pos {
+                at: next,
+                dir: pos.dir,
+            });
+        }
+        let off_cube = || {
+            let message = format!("({}, {}) is on no face of the cube", pos.at.x, pos.at.y);
+            SolveError::no_solution(message)
+        };
+        // Retrieve origin face
+        let org_face = self.get_face(pos.at).ok_or_else(off_cube)?;
+        // Retrieve destination face
+        let (dest_face_id, _) = Self::neighbour(org_face.id.clone(), org_face.dir, pos.dir);
+        let dest_face = self.faces.get(&dest_face_id).ok_or_else(off_cube)?;
+        // Find the two common corners of the two faces
+        let org_corners = org_face.corners();
+        let dest_corners = dest_face.corners();
+        let org_corners_ids: HashSet<&CornerId> = org_corners.keys().collect();
+        let dest_corners_ids: HashSet<&CornerId> = dest_corners.keys().collect();
+        let common_corners_ids: Vec<&&CornerId> =
+            org_corners_ids.intersection(&dest_corners_ids).collect();
+        // Determine the matrix of rotation between the two faces
+        let org_vec = org_corners[common_corners_ids[1]] - org_corners[common_corners_ids[0]];
+        let dest_vec = dest_corners[common_corners_ids[1]] - dest_corners[common_corners_ids[0]];
+        let determinant = org_vec.x * dest_vec.y - org_vec.y * dest_vec.x;
+        let scalar = org_vec.x * dest_vec.x + org_vec.y * dest_vec.y;
+        let (mat, dir) = if determinant == 0 {
+            if scalar >= 0 {
+                ((1, 0, 0, 1), pos.dir)
+            } else {
+                ((-1, 0, 0, -1), pos.dir.u_turn())
+            }
+        } else if determinant > 0 {
+            ((0, -1, 1, 0), pos.dir.turn_right())
+        } else {
+            ((0, 1, -1, 0), pos.dir.turn_left())
+        };
+        let rotate = |p: Point2| Point2::new(mat.0 * p.x + mat.1 * p.y, mat.2 * p.x + mat.3 * p.y);
+        // Move the center of the next tile across the common edge
+        let center = next * 2 + Point2::new(1, 1) - org_corners[common_corners_ids[0]];
+        let center = rotate(center) + dest_corners[common_corners_ids[0]];
+        Ok(Pos {
+            at: Point2::new((center.x - 1) / 2, (center.y - 1) / 2),
+            dir,
+        })
+    }
+}
+
+fn start(board: &Board) -> Pos {
+    Pos {
+        at: Point2::new(board.hdims[0].0, 0),
+        dir: Direction::Right,
+    }
+}
+
+fn password(pos: &Pos) -> isize {
+    1000 * (pos.at.y + 1) + 4 * (pos.at.x + 1) + pos.dir as isize
+}
+
+pub struct Input {
+    board: Board,
+    commands: Vec<Command>,
+}
+
+pub struct Day22;
+
+impl Solver for Day22 {
+    type Input = Input;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let board = Board::parse(input)?;
+        let numlines = input.lines().count();
+        let line = match input.lines().last() {
+            Some(line) if numlines > board.tiles.height() => line,
+            _ => return Err(SolveError::at_end(input, "expected a path")),
+        };
+        // Split the path into numbers and single letters.
+        let mut commands: Vec<Command> = Vec::new();
+        let mut start = 0;
+        while start < line.len() {
+            let digits = line[start..]
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(line.len() - start);
+            let end = start + digits.max(line[start..].chars().next().unwrap().len_utf8());
+            let command = Command::parse(line, &line[start..end]);
+            commands.push(command.map_err(|err| err.after_lines(numlines - 1))?);
+            start = end;
+        }
+        Ok(Input { board, commands })
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let mut pos = start(&input.board);
+        for command in &input.commands {
+            pos.execute(command, &input.board)?;
+        }
+        Ok(password(&pos).into())
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let mut pos = start(&input.board);
+        let cube = Cube::new(&input.board)?;
+        for command in &input.commands {
+            pos.execute(command, &cube)?;
+        }
+        Ok(password(&pos).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_generated};
+    use std::fs::read_to_string;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        solve::<Day22>(&read_to_string("./data/example.txt").unwrap(), part)
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(1), Ok(Answer::Int(6032)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(2), Ok(Answer::Int(5031)));
+    }
+
+    type Vec3 = [isize; 3];
+
+    fn add(a: Vec3, b: Vec3, times: isize) -> Vec3 {
+        [
+            a[0] + times * b[0],
+            a[1] + times * b[1],
+            a[2] + times * b[2],
+        ]
+    }
+
+    fn cross(a: Vec3, b: Vec3) -> Vec3 {
+        [
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ]
+    }
+
+    // The password at the end of the path, with `step` giving the column, row
+    // and facing after one move, walls aside.
+    fn follow(
+        board: &[&[u8]],
+        path: &str,
+        step: impl Fn((isize, isize, usize)) -> (isize, isize, usize),
+    ) -> isize {
+        let x = board[0].iter().position(|&tile| tile == b'.').unwrap();
+        let mut pos = (x as isize, 0, 0);
+        let mut moves = 0;
+        for car in path.trim().chars().chain([' ']) {
+            if let Some(digit) = car.to_digit(10) {
+                moves = moves * 10 + digit;
+                continue;
+            }
+            for _ in 0..moves {
+                let next = step(pos);
+                if board[next.1 as usize][next.0 as usize] == b'#' {
+                    break;
+                }
+                pos = next;
+            }
+            moves = 0;
+            pos.2 = match car {
+                'R' => (pos.2 + 1) % 4,
+                'L' => (pos.2 + 3) % 4,
+                _ => pos.2,
+            };
+        }
+        1000 * (pos.1 + 1) + 4 * (pos.0 + 1) + pos.2 as isize
+    }
+
+    #[test]
+    fn generated_inputs() {
+        // Against walking back across the board to wrap around for part 1,
+        // and for part 2, against folding the net into a cube in space, the
+        // centers of the tiles at odd coordinates and its faces at 0 or twice
+        // their size, so that a move off a face is half a move to its edge
+        // then half a move into the cube.
+        const STEPS: [(isize, isize); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+        for (input, answers) in solve_generated::<Day22>(0..3, 20, &(), &[1, 2]) {
+            let (board, path) = input.split_once("\n\n").unwrap();
+            let board: Vec<&[u8]> = board.lines().map(str::as_bytes).collect();
+            let tile = |x: isize, y: isize| {
+                let row = board.get(y as usize).copied().unwrap_or_default();
+                row.get(x as usize).copied().unwrap_or(b' ')
+            };
+
+            let wrap = |(x, y, facing): (isize, isize, usize)| {
+                let (dx, dy) = STEPS[facing];
+                if tile(x + dx, y + dy) != b' ' {
+                    return (x + dx, y + dy, facing);
+                }
+                let (mut x, mut y) = (x, y);
+                while tile(x - dx, y - dy) != b' ' {
+                    (x, y) = (x - dx, y - dy);
+                }
+                (x, y, facing)
+            };
+
+            let area = board
+                .iter()
+                .copied()
+                .flatten()
+                .filter(|&&t| t != b' ')
+                .count();
+            let size = (1..).find(|size| 6 * size * size >= area).unwrap() as isize;
+            // The corner of each face at the top left of the net, then the
+            // directions of its right and bottom sides.
+            let first = (
+                board[0].iter().position(|&t| t != b' ').unwrap() as isize / size,
+                0,
+            );
+            let mut faces = HashMap::from([(first, ([0, 0, 0], [1, 0, 0], [0, 1, 0]))]);
+            let mut todo = vec![first];
+            while let Some((fx, fy)) = todo.pop() {
+                let (corner, right, down) = faces[&(fx, fy)];
+                let inward = cross(right, down);
+                let outward = add([0; 3], inward, -1);
+                for (next, face) in [
+                    ((fx + 1, fy), (add(corner, right, 2 * size), inward, down)),
+                    ((fx - 1, fy), (add(corner, inward, 2 * size), outward, down)),
+                    ((fx, fy + 1), (add(corner, down, 2 * size), right, inward)),
+                    (
+                        (fx, fy - 1),
+                        (add(corner, inward, 2 * size), right, outward),
+                    ),
+                ] {
+                    if tile(next.0 * size, next.1 * size) != b' ' && !faces.contains_key(&next) {
+                        faces.insert(next, face);
+                        todo.push(next);
+                    }
+                }
+            }
+            let spot = |x: isize, y: isize, facing: usize| {
+                let (corner, right, down) = faces[&(x / size, y / size)];
+                let center = add(corner, right, 2 * (x % size) + 1);
+                let center = add(center, down, 2 * (y % size) + 1);
+                let dirs = [right, down, add([0; 3], right, -1), add([0; 3], down, -1)];
+                (center, dirs[facing], cross(right, down))
+            };
+            let tiles: HashMap<Vec3, (isize, isize)> = (0..board.len() as isize)
+                .flat_map(|y| (0..board[y as usize].len() as isize).map(move |x| (x, y)))
+                .filter(|&(x, y)| tile(x, y) != b' ')
+                .map(|(x, y)| (spot(x, y, 0).0, (x, y)))
+                .collect();
+            let fold = |(x, y, facing): (isize, isize, usize)| {
+                let (center, dir, inward) = spot(x, y, facing);
+                if let Some(&(x, y)) = tiles.get(&add(center, dir, 2)) {
+                    return (x, y, facing);
+                }
+                let (x, y) = tiles[&add(add(center, dir, 1), inward, 1)];
+                let facing = (0..4).find(|&f| spot(x, y, f).1 == inward).unwrap();
+                (x, y, facing)
+            };
+
+            let expected = [follow(&board, path, wrap), follow(&board, path, fold)];
+            assert_eq!(answers, [expected[0].into(), expected[1].into()]);
+        }
+    }
+
+    #[test]
+    fn part2_single_tile_faces() {
+        // Right onto the right face, heading down, then left onto the front.
+        let board = " .\n...\n .\n .\n\n";
+        assert_eq!(
+            solve::<Day22>(&format!("{}1R1\n", board), 2),
+            Ok(Answer::Int(2010))
+        );
+        // Then down onto the bottom face, heading left.
+        assert_eq!(
+            solve::<Day22>(&format!("{}2\n", board), 2),
+            Ok(Answer::Int(3010))
+        );
+    }
+
+    #[test]
+    fn part2_not_a_cube() {
+        let face = vec![".".repeat(50); 50].join("\n");
+        let error = solve::<Day22>(&format!("{}\n\n100\n", face), 2);
+        let message = "the board does not fold into a cube";
+        assert_eq!(error, Err(SolveError::no_solution(message)));
+    }
+}
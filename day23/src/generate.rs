@@ -0,0 +1,26 @@
+use crate::Day23;
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// A square grove of `size` by `size` tiles, at least 1, about half of them
+// holding an elf.
+impl Generator for Day23 {
+    const SIZE: usize = 70;
+
+    fn generate(rng: &mut StdRng, size: usize, _params: &Self::Params) -> String {
+        let size = size.max(1);
+        let mut tiles: Vec<Vec<char>> = (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| if rng.gen_bool(0.5) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        tiles[rng.gen_range(0..size)][rng.gen_range(0..size)] = '#';
+        tiles
+            .iter()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect()
+    }
+}
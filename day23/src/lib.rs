@@ -0,0 +1,223 @@
+use aoc_common::trace::{self, Frame};
+use aoc_common::{Answer, Direction, Grid, Point2, SolveError, Solver};
+use std::collections::HashMap;
+
+mod generate;
+
+type Positions = HashMap<Point2, Option<Point2>>;
+type Hints = HashMap<Point2, isize>;
+
+// North, south, west then east.
+const STRATEGY: [Direction; 4] = [
+    Direction::Up,
+    Direction::Down,
+    Direction::Left,
+    Direction::Right,
+];
+
+fn get_wish(pos: &Point2, positions: &Positions, strategy: usize) -> Option<Point2> {
+    // If adjacent Elves, do not move.
+    let has_neighbours = pos.neighbours8().any(|p| positions.contains_key(&p));
+    if !has_neighbours {
+        return None;
+    };
+    // Look for possible move.
+    for i in 0..4 {
+        let dir = STRATEGY[(i + strategy) % 4];
+        let ahead = pos.step(dir);
+        let side = dir.turn_right().offset();
+        let occupied = [ahead - side, ahead, ahead + side]
+            .iter()
+            .any(|p| positions.contains_key(p));
+        if !occupied {
+            return Some(ahead);
+        }
+    }
+    None
+}
+
+fn get_wishes(positions: &Positions, strategy: usize) -> (Positions, Hints) {
+    let mut new_positions: Positions = HashMap::new();
+    let mut wishes: Hints = HashMap::new();
+    for pos in positions.keys() {
+        let wish = get_wish(pos, positions, strategy);
+        if let Some(wish_pos) = wish {
+            wishes.entry(wish_pos).and_modify(|e| *e += 1).or_insert(1);
+            new_positions.insert(*pos, wish);
+        } else {
+            new_positions.insert(*pos, None);
+        }
+    }
+    (new_positions, wishes)
+}
+
+fn do_moves(positions: &Positions, wishes: &Hints) -> Positions {
+    let mut new_positions = HashMap::new();
+    for (pos, wish) in positions {
+        if let Some(wish_pos) = wish {
+            if wishes[wish_pos] == 1 {
+                new_positions.insert(*wish_pos, None);
+                continue;
+            }
+        }
+        new_positions.insert(*pos, None);
+    }
+    new_positions
+}
+
+fn frame(round: usize, positions: &Positions) -> Frame {
+    let elves = positions.keys().map(|pos| (*pos, '#'));
+    Frame::from_points(format!("round {}", round), elves)
+}
+
+pub struct Day23;
+
+impl Solver for Day23 {
+    type Input = Positions;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let grid = Grid::parse(input, "'#' or '.'", |_, car| match car {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        let positions: Positions = grid
+            .points()
+            .filter(|pos| grid[*pos])
+            .map(|pos| (pos, None))
+            .collect();
+        if positions.is_empty() {
+            return Err(SolveError::at_end(input, "expected at least one elf"));
+        }
+        Ok(positions)
+    }
+
+    fn part1(positions: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let mut positions = positions.clone();
+        for i in 0..10 {
+            let wishes;
+            (positions, wishes) = get_wishes(&positions, i);
+            positions = do_moves(&positions, &wishes);
+            trace::trace(|| frame(i + 1, &positions));
+        }
+        let xmin = positions.keys().map(|p| p.x).min().unwrap();
+        let xmax = positions.keys().map(|p| p.x).max().unwrap();
+        let ymin = positions.keys().map(|p| p.y).min().unwrap();
+        let ymax = positions.keys().map(|p| p.y).max().unwrap();
+        let result = (xmax - xmin + 1) * (ymax - ymin + 1) - positions.len() as isize;
+        Ok(result.into())
+    }
+
+    fn part2(positions: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let mut positions = positions.clone();
+        let mut newpositions;
+        let mut wishes;
+        let mut i = 0;
+        loop {
+            (newpositions, wishes) = get_wishes(&positions, i);
+            newpositions = do_moves(&newpositions, &wishes);
+            if positions == newpositions {
+                break;
+            }
+            i += 1;
+            positions = newpositions;
+            trace::trace(|| frame(i, &positions));
+        }
+        Ok((i + 1).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_generated};
+    use std::collections::HashSet;
+    use std::fs::read_to_string;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        solve::<Day23>(&read_to_string("./data/example.txt").unwrap(), part)
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(1), Ok(Answer::Int(110)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(2), Ok(Answer::Int(20)));
+    }
+
+    // The elves after one round, each looking at the three tiles on the
+    // north, south, west then east side, starting one side later each round.
+    fn spread(elves: &HashSet<(isize, isize)>, round: usize) -> HashSet<(isize, isize)> {
+        let sides = [
+            [(-1, -1), (0, -1), (1, -1)],
+            [(-1, 1), (0, 1), (1, 1)],
+            [(-1, -1), (-1, 0), (-1, 1)],
+            [(1, -1), (1, 0), (1, 1)],
+        ];
+        let free = |(x, y): (isize, isize), tiles: &[(isize, isize)]| {
+            tiles
+                .iter()
+                .all(|(dx, dy)| !elves.contains(&(x + dx, y + dy)))
+        };
+        let proposals: Vec<_> = elves
+            .iter()
+            .map(|&(x, y)| {
+                if sides.iter().all(|side| free((x, y), side)) {
+                    return (x, y);
+                }
+                (0..4)
+                    .map(|i| sides[(round + i) % 4])
+                    .find(|side| free((x, y), side))
+                    .map_or((x, y), |side| (x + side[1].0, y + side[1].1))
+            })
+            .collect();
+        elves
+            .iter()
+            .zip(&proposals)
+            .map(|(elf, proposal)| {
+                let wanted = proposals.iter().filter(|p| *p == proposal).count();
+                if wanted == 1 {
+                    *proposal
+                } else {
+                    *elf
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn generated_inputs() {
+        // Against a round by round simulation on a set of elves.
+        for (input, answers) in solve_generated::<Day23>(0..10, 10, &(), &[1, 2]) {
+            let mut elves: HashSet<(isize, isize)> = HashSet::new();
+            for (y, line) in input.lines().enumerate() {
+                for (x, car) in line.chars().enumerate() {
+                    if car == '#' {
+                        elves.insert((x as isize, y as isize));
+                    }
+                }
+            }
+            let (mut round, mut still, mut empty) = (0, None, None);
+            while still.is_none() || empty.is_none() {
+                let next = spread(&elves, round);
+                round += 1;
+                if next == elves {
+                    still = still.or(Some(round));
+                }
+                if round == 10 {
+                    let xs = next.iter().map(|e| e.0);
+                    let ys = next.iter().map(|e| e.1);
+                    let width = xs.clone().max().unwrap() - xs.min().unwrap() + 1;
+                    let height = ys.clone().max().unwrap() - ys.min().unwrap() + 1;
+                    empty = Some(width * height - next.len() as isize);
+                }
+                elves = next;
+            }
+            assert_eq!(answers, [empty.unwrap().into(), still.unwrap().into()]);
+        }
+    }
+}
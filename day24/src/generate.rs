@@ -0,0 +1,35 @@
+use crate::Day24;
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// A valley `size` tiles wide, at least 3, and a fifth of it high but at least 5, with a
+// blizzard on about a quarter of its tiles. As in the puzzle, no blizzard
+// goes up or down in the columns of the entrance and the exit.
+impl Generator for Day24 {
+    const SIZE: usize = 120;
+
+    fn generate(rng: &mut StdRng, size: usize, _params: &Self::Params) -> String {
+        let width = size.max(3);
+        let height = (width / 5).max(5);
+        let mut valley = String::new();
+        for y in 0..height {
+            for x in 0..width {
+                let tile = if (y == 0 && x == 1) || (y == height - 1 && x == width - 2) {
+                    '.'
+                } else if y == 0 || y == height - 1 || x == 0 || x == width - 1 {
+                    '#'
+                } else if !rng.gen_bool(0.25) {
+                    '.'
+                } else if x == 1 || x == width - 2 {
+                    ['<', '>'][rng.gen_range(0..2)]
+                } else {
+                    ['<', '>', '^', 'v'][rng.gen_range(0..4)]
+                };
+                valley.push(tile);
+            }
+            valley.push('\n');
+        }
+        valley
+    }
+}
@@ -0,0 +1,305 @@
+use aoc_common::trace::{self, Frame};
+use aoc_common::{Answer, Direction, Grid, Point2, SolveError, Solver};
+use pathfinding::prelude::astar;
+use std::collections::HashSet;
+
+mod generate;
+
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+struct TimePos(Point2, usize);
+
+#[derive(Debug, Clone)]
+struct Blizzard {
+    dir: Direction,
+    pos: Point2,
+}
+
+struct TimeMap {
+    t: usize,
+    blizzards_t: Vec<Blizzard>,
+    maps: Vec<HashSet<Point2>>,
+    xdim: isize,
+    ydim: isize,
+    x0: isize,
+}
+
+impl TimeMap {
+    fn new(blizzards: Vec<Blizzard>, xdim: isize, ydim: isize, x0: isize) -> Self {
+        let map: HashSet<Point2> = blizzards.iter().map(|blizzard| blizzard.pos).collect();
+        Self {
+            t: 0,
+            blizzards_t: blizzards,
+            maps: vec![map],
+            xdim,
+            ydim,
+            x0,
+        }
+    }
+
+    fn on_board(&self, pos: Point2) -> bool {
+        0 <= pos.x && pos.x < self.xdim && 0 <= pos.y && pos.y < self.ydim
+    }
+
+    fn map(&mut self, t: usize) -> &HashSet<Point2> {
+        // Compjute all intermediate maps if needed.
+        while self.t < t {
+            self.t += 1;
+            self.blizzards_t = self
+                .blizzards_t
+                .iter()
+                .map(|blizzard| {
+                    let pos = blizzard.pos.step(blizzard.dir);
+                    Blizzard {
+                        dir: blizzard.dir,
+                        pos: Point2::new(pos.x.rem_euclid(self.xdim), pos.y.rem_euclid(self.ydim)),
+                    }
+                })
+                .collect();
+            let map_t: HashSet<Point2> = self
+                .blizzards_t
+                .iter()
+                .map(|blizzard| blizzard.pos)
+                .collect();
+            self.maps.push(map_t);
+            trace::trace(|| self.frame());
+        }
+        &self.maps[t]
+    }
+
+    // The valley at the last computed minute, with the number of blizzards
+    // where there are several.
+    fn frame(&self) -> Frame {
+        let (width, height) = (self.xdim as usize + 2, self.ydim as usize + 2);
+        let mut cells = Grid::new(width, height, '#');
+        for pos in (0..self.ydim).flat_map(|y| (0..self.xdim).map(move |x| Point2::new(x, y))) {
+            cells[pos + Point2::new(1, 1)] = '.';
+        }
+        cells[Point2::new(self.x0 + 1, 0)] = '.';
+        cells[Point2::new(self.xdim, self.ydim + 1)] = '.';
+        for blizzard in &self.blizzards_t {
+            let cell = &mut cells[blizzard.pos + Point2::new(1, 1)];
+            *cell = match *cell {
+                '.' => match blizzard.dir {
+                    Direction::Left => '<',
+                    Direction::Up => '^',
+                    Direction::Down => 'v',
+                    Direction::Right => '>',
+                },
+                '<' | '^' | 'v' | '>' => '2',
+                count => char::from_digit(count.to_digit(10).unwrap() + 1, 10).unwrap(),
+            };
+        }
+        Frame::new(format!("minute {}", self.t), cells)
+    }
+
+    fn successors(&mut self, tpos: &TimePos) -> Vec<(TimePos, isize)> {
+        let TimePos(pos, t) = *tpos;
+        let (start, finish) = (
+            Point2::new(self.x0, -1),
+            Point2::new(self.xdim - 1, self.ydim),
+        );
+        let mut successors = Vec::new();
+        // Wait in place
+        if !self.map(t + 1).contains(&pos) {
+            successors.push((TimePos(pos, t + 1), 1));
+        }
+        // Move from start to board
+        let down = pos.step(Direction::Down);
+        if pos.y == -1 && !self.map(t + 1).contains(&down) {
+            successors.push((TimePos(down, t + 1), 1));
+        }
+        // Move from board to finish
+        if down == finish {
+            successors.push((TimePos(down, t + 1), 1));
+        }
+        // Move from one board cell to another
+        if self.on_board(pos) {
+            for next in pos.neighbours4() {
+                if !self.map(t + 1).contains(&next) && self.on_board(next) {
+                    successors.push((TimePos(next, t + 1), 1));
+                }
+            }
+        }
+        // Move from board to start
+        let up = pos.step(Direction::Up);
+        if up == start {
+            successors.push((TimePos(up, t + 1), 1));
+        }
+        // Move from finish to board
+        if pos == finish && !self.map(t + 1).contains(&up) {
+            successors.push((TimePos(up, t + 1), 1));
+        }
+        successors
+    }
+}
+
+pub struct Input {
+    blizzards: Vec<Blizzard>,
+    xdim: isize,
+    ydim: isize,
+    x0: isize,
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+    a / x * b
+}
+
+// Duration of the trip between the start and the finish, leaving at time t.
+fn trip(timemap: &mut TimeMap, t: usize, forth: bool) -> Result<usize, SolveError> {
+    let start = Point2::new(timemap.x0, -1);
+    let finish = Point2::new(timemap.xdim - 1, timemap.ydim);
+    let (from, to) = if forth {
+        (start, finish)
+    } else {
+        (finish, start)
+    };
+    // Blizzards come back to the same places every `period` minutes, so a trip
+    // taking longer than this would go through the same state twice.
+    let period = lcm(timemap.xdim as usize, timemap.ydim as usize);
+    let limit = t + period * (timemap.xdim * timemap.ydim + 2) as usize;
+    let result = astar(
+        &TimePos(from, t),
+        |tpos| match tpos.1 < limit {
+            true => timemap.successors(tpos),
+            false => Vec::new(),
+        },
+        |tpos| tpos.0.manhattan(to) as isize,
+        |tpos| tpos.0 == to,
+    );
+    match result {
+        Some((_, duration)) => Ok(duration as usize),
+        None => Err(SolveError::no_solution("the blizzards block the way")),
+    }
+}
+
+pub struct Day24;
+
+impl Solver for Day24 {
+    type Input = Input;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let valley = Grid::parse(input, "'#', '.' or a blizzard", |_, car| match car {
+            '<' => Some(Some(Direction::Left)),
+            '^' => Some(Some(Direction::Up)),
+            'v' => Some(Some(Direction::Down)),
+            '>' => Some(Some(Direction::Right)),
+            '.' | '#' => Some(None),
+            _ => None,
+        })?;
+        // Positions are relative to the inside of the walls.
+        let blizzards = valley
+            .points()
+            .filter_map(|pos| {
+                valley[pos].map(|dir| Blizzard {
+                    dir,
+                    pos: pos - Point2::new(1, 1),
+                })
+            })
+            .collect();
+        let xdim = valley.width() as isize - 2;
+        let ydim = valley.height() as isize - 2;
+        let x0 = match input.lines().next().unwrap_or_default().find('.') {
+            Some(x) if xdim > 0 && ydim > 0 => x as isize - 1,
+            _ => {
+                return Err(SolveError::at_end(
+                    input,
+                    "expected a valley with an entrance",
+                ))
+            }
+        };
+        Ok(Input {
+            blizzards,
+            xdim,
+            ydim,
+            x0,
+        })
+    }
+
+    fn part1(input: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let mut timemap = TimeMap::new(input.blizzards.clone(), input.xdim, input.ydim, input.x0);
+        Ok(trip(&mut timemap, 0, true)?.into())
+    }
+
+    fn part2(input: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let mut timemap = TimeMap::new(input.blizzards.clone(), input.xdim, input.ydim, input.x0);
+        let first = trip(&mut timemap, 0, true)?;
+        let back = trip(&mut timemap, first, false)?;
+        let second = trip(&mut timemap, first + back, true)?;
+        Ok((first + back + second).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_generated};
+    use std::fs::read_to_string;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        solve::<Day24>(&read_to_string("./data/example.txt").unwrap(), part)
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(1), Ok(Answer::Int(18)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(example(2), Ok(Answer::Int(54)));
+    }
+
+    // The minutes to go from `from` to `to` leaving at minute `t`, following
+    // every tile the expedition could be on, minute by minute. A blizzard on a
+    // tile at minute `t` started `t` tiles behind it along its row or column.
+    // Stepping off the top or the left wraps to a tile out of the valley.
+    fn walk(valley: &[&[u8]], from: (usize, usize), to: (usize, usize), t: usize) -> usize {
+        let (width, height) = (valley[0].len() - 2, valley.len() - 2);
+        let free = |(x, y): (usize, usize), t: usize| {
+            if (x, y) == from || (x, y) == to {
+                return true;
+            }
+            if x == 0 || x > width || y == 0 || y > height {
+                return false;
+            }
+            let (x0, y0) = (x - 1, y - 1);
+            valley[y][(x0 + width - t % width) % width + 1] != b'>'
+                && valley[y][(x0 + t) % width + 1] != b'<'
+                && valley[(y0 + height - t % height) % height + 1][x] != b'v'
+                && valley[(y0 + t) % height + 1][x] != b'^'
+        };
+        let mut tiles = HashSet::from([from]);
+        let mut minutes = 0;
+        while !tiles.contains(&to) {
+            minutes += 1;
+            tiles = tiles
+                .iter()
+                .flat_map(|&(x, y)| {
+                    let (left, up) = (x.wrapping_sub(1), y.wrapping_sub(1));
+                    [(x, y), (x + 1, y), (x, y + 1), (left, y), (x, up)]
+                })
+                .filter(|&tile| free(tile, t + minutes))
+                .collect();
+        }
+        minutes
+    }
+
+    #[test]
+    fn generated_inputs() {
+        // Against following all the tiles the expedition can reach.
+        for (input, answers) in solve_generated::<Day24>(0..3, 10, &(), &[1, 2]) {
+            let valley: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+            let start = (1, 0);
+            let finish = (valley[0].len() - 2, valley.len() - 1);
+            let first = walk(&valley, start, finish, 0);
+            let back = walk(&valley, finish, start, first);
+            let second = walk(&valley, start, finish, first + back);
+            assert_eq!(answers, [first.into(), (first + back + second).into()]);
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{Day25, Snafu};
+use aoc_common::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// `size` SNAFU numbers.
+impl Generator for Day25 {
+    const SIZE: usize = 120;
+
+    fn generate(rng: &mut StdRng, size: usize, _params: &Self::Params) -> String {
+        (0..size)
+            .map(|_| {
+                let digits = rng.gen_range(1..=12);
+                let number = Snafu::from_int(rng.gen_range(1..5_isize.pow(digits)));
+                format!("{}\n", number)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,192 @@
+use aoc_common::{parse_lines, Answer, SolveError, Solver};
+use std::fmt;
+use std::fmt::Display;
+
+mod generate;
+
+struct Digit {
+    val: isize,
+}
+
+impl Digit {
+    fn from_car(car: char) -> Option<Self> {
+        let val = match car {
+            '2' => 2,
+            '1' => 1,
+            '0' => 0,
+            '-' => -1,
+            '=' => -2,
+            _ => return None,
+        };
+        Some(Self { val })
+    }
+
+    fn from_int(val: isize) -> Self {
+        if !(-2..=2).contains(&val) {
+            panic!("Unsupported val");
+        }
+        Self { val }
+    }
+
+    fn to_car(&self) -> char {
+        match self.val {
+            2 => '2',
+            1 => '1',
+            0 => '0',
+            -1 => '-',
+            -2 => '=',
+            _ => panic!("Unsupported val!"),
+        }
+    }
+
+    fn to_int(&self) -> isize {
+        self.val
+    }
+}
+
+pub struct Snafu {
+    val: isize,
+}
+
+impl Snafu {
+    fn from_string(snafu: &str) -> Result<Self, SolveError> {
+        let mut val: isize = 0;
+        for (i, car) in snafu.char_indices() {
+            let digit = Digit::from_car(car)
+                .ok_or_else(|| SolveError::at(snafu, &snafu[i..], "expected 2, 1, 0, - or ="))?;
+            val = val
+                .checked_mul(5)
+                .and_then(|val| val.checked_add(digit.to_int()))
+                .ok_or_else(|| SolveError::at(snafu, snafu, "number too large"))?;
+        }
+        Ok(Snafu { val })
+    }
+
+    fn from_int(val: isize) -> Self {
+        Self { val }
+    }
+
+    fn to_int(&self) -> isize {
+        self.val
+    }
+}
+
+impl Display for Snafu {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut value = self.val;
+        let mut res = Vec::new();
+        // Digits from the lowest, carrying into the next one for -1 and -2.
+        while value != 0 {
+            let digit = match value.rem_euclid(5) {
+                3 => -2,
+                4 => -1,
+                digit => digit,
+            };
+            res.push(Digit::from_int(digit).to_car());
+            value = value.div_euclid(5) + (digit < 0) as isize;
+        }
+        let res: String = res.iter().rev().collect();
+        write!(f, "{}", res)
+    }
+}
+
+pub struct Day25;
+
+impl Solver for Day25 {
+    type Input = Vec<Snafu>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_lines(input, Snafu::from_string)
+    }
+
+    fn part1(numbers: &Self::Input, _params: &Self::Params) -> Result<Answer, SolveError> {
+        let total = numbers
+            .iter()
+            .try_fold(0_isize, |total, snafu| total.checked_add(snafu.to_int()))
+            .ok_or_else(|| SolveError::no_solution("the sum is too large"))?;
+        Ok(Snafu::from_int(total).to_string().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_generated};
+    use std::fs::read_to_string;
+
+    fn example(part: u8) -> Result<Answer, SolveError> {
+        solve::<Day25>(&read_to_string("./data/example.txt").unwrap(), part)
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(example(1), Ok("2=-1=0".into()));
+    }
+
+    #[test]
+    fn int_to_snafu() {
+        assert_eq!(Snafu::from_int(1).to_string(), "1");
+        assert_eq!(Snafu::from_int(2).to_string(), "2");
+        assert_eq!(Snafu::from_int(3).to_string(), "1=");
+        assert_eq!(Snafu::from_int(4).to_string(), "1-");
+        assert_eq!(Snafu::from_int(5).to_string(), "10");
+        assert_eq!(Snafu::from_int(6).to_string(), "11");
+        assert_eq!(Snafu::from_int(7).to_string(), "12");
+        assert_eq!(Snafu::from_int(8).to_string(), "2=");
+        assert_eq!(Snafu::from_int(9).to_string(), "2-");
+        assert_eq!(Snafu::from_int(10).to_string(), "20");
+        assert_eq!(Snafu::from_int(15).to_string(), "1=0");
+        assert_eq!(Snafu::from_int(20).to_string(), "1-0");
+        assert_eq!(Snafu::from_int(2022).to_string(), "1=11-2");
+        assert_eq!(Snafu::from_int(12345).to_string(), "1-0---0");
+        assert_eq!(Snafu::from_int(314159265).to_string(), "1121-1110-1=0");
+    }
+
+    #[test]
+    fn int_to_snafu_and_back_many_times() {
+        for i in 0..10000 {
+            let snafu = Snafu::from_int(i).to_string();
+            let val = Snafu::from_string(&snafu).unwrap().to_int();
+            assert_eq!(i, val);
+        }
+    }
+
+    #[test]
+    fn too_large() {
+        // 28 digits do not fit, 27 do but not 3 times over.
+        let input = format!("1\n{}\n", "2".repeat(28));
+        let error = SolveError::at("", "", "number too large").after_lines(1);
+        assert_eq!(solve::<Day25>(&input, 1), Err(error));
+        let input = format!("{0}\n{0}\n{0}\n", "2".repeat(27));
+        let error = SolveError::no_solution("the sum is too large");
+        assert_eq!(solve::<Day25>(&input, 1), Err(error));
+        for val in [isize::MAX, isize::MAX - 2, 4 * 5_isize.pow(26)] {
+            let snafu = Snafu::from_int(val).to_string();
+            assert_eq!(Snafu::from_string(&snafu).unwrap().to_int(), val);
+        }
+    }
+
+    #[test]
+    fn check_input_file() {
+        let input = read_to_string("./data/input.txt").unwrap();
+        for line in input.lines() {
+            assert_eq!(line, Snafu::from_string(line).unwrap().to_string());
+        }
+    }
+
+    #[test]
+    fn generated_inputs() {
+        // The answer decoded digit by digit, against the sum of the numbers.
+        let decode = |snafu: &str| {
+            snafu.chars().fold(0, |val, car| {
+                5 * val + "=-012".find(car).unwrap() as i64 - 2
+            })
+        };
+        let solved = solve_generated::<Day25>(0..10, 20, &(), &[1]);
+        for (input, answers) in solved {
+            let total: i64 = input.lines().map(decode).sum();
+            assert_eq!(decode(&answers[0].to_string()), total);
+        }
+    }
+}
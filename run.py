@@ -6,25 +6,23 @@ from pathlib import Path
 
 app = typer.Typer()
 
+ROOT = Path(__file__).parent
+
 @app.command()
 def build():
-    dirs = [d for d in Path(__file__).parent.iterdir() if d.is_dir() and d.name != ".git"]
-    dirs = sorted(dirs)
-    for p in dirs:
-        if not p.is_dir():
-            continue
-        print(f"\n## {p.name}:")
-        os.system(f"cd {p}; cargo build --release")
+    os.system(f"cd {ROOT}; cargo build --release")
+
+@app.command()
+def run(jobs: int = 1):
+    os.system(f"cd {ROOT}; ./target/release/aoc22 run --jobs {jobs}")
+
+@app.command()
+def verify():
+    os.system(f"cd {ROOT}; ./target/release/aoc22 verify")
 
 @app.command()
-def run():
-    dirs = [d for d in Path(__file__).parent.iterdir() if d.is_dir() and d.name != ".git"]
-    dirs = sorted(dirs)
-    for p in dirs:
-        if not p.is_dir():
-            continue
-        print(f"\n## {p.name}:")
-        os.system(f"cd {Path(__file__).parent.joinpath(p)}; ./target/release/{p.name}")
+def bench(runs: int = 10):
+    os.system(f"cd {ROOT}; ./target/release/aoc22 bench --runs {runs}")
 
 if __name__ == '__main__':
     app()